@@ -0,0 +1,283 @@
+//! Collapsible sections with a header button each.
+//!
+//! Headers are buttons with `aria-expanded` and `aria-controls`, panels are
+//! `role=region` elements labelled by their header. Panels stay mounted and
+//! expand and collapse by transitioning `grid-template-rows`, so the height is
+//! animated without measuring the content. Arrow Up and Down, Home and End
+//! move between the headers.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::vdom::{button, div, h3};
+
+/// A section with its header and content.
+#[derive(Clone, PartialEq)]
+pub struct Section {
+    pub title: AttrValue,
+    pub content: Html,
+    pub disabled: bool,
+}
+
+impl Section {
+    pub fn new(title: impl Into<AttrValue>, content: impl Into<Html>) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            disabled: false,
+        }
+    }
+
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The expanded sections after toggling section `i`. Unless `multiple`
+/// sections can be expanded, expanding a section collapses the others.
+///
+/// ```
+/// use yew_utils::components::accordion::toggle;
+///
+/// assert_eq!(toggle(&[0], 2, false), vec![2]);
+/// assert_eq!(toggle(&[0], 2, true), vec![0, 2]);
+/// assert_eq!(toggle(&[0, 2], 0, true), vec![2]);
+/// assert_eq!(toggle(&[2], 2, false), Vec::<usize>::new());
+/// ```
+pub fn toggle(expanded: &[usize], i: usize, multiple: bool) -> Vec<usize> {
+    if expanded.contains(&i) {
+        return expanded.iter().copied().filter(|j| *j != i).collect();
+    }
+    let mut expanded = match multiple {
+        true => expanded.to_vec(),
+        false => Vec::new(),
+    };
+    expanded.push(i);
+    expanded.sort_unstable();
+    expanded
+}
+
+/// The header that `key` moves the focus to from `current`, skipping
+/// disabled sections.
+fn navigate(disabled: &[bool], current: usize, key: &str) -> Option<usize> {
+    let count = disabled.len();
+    let enabled = |i: &usize| !disabled[*i];
+    match key {
+        "ArrowDown" => (1..=count)
+            .map(|step| (current + step) % count)
+            .find(enabled),
+        "ArrowUp" => (1..=count)
+            .map(|step| (current + count - step) % count)
+            .find(enabled),
+        "Home" => (0..count).find(enabled),
+        "End" => (0..count).rev().find(enabled),
+        _ => None,
+    }
+}
+
+/// Sections that expand and collapse when their header is clicked.
+///
+/// The expanded sections are either controlled by `expanded` or kept by the
+/// component, starting with `initial`. Unless `multiple` is set, only one
+/// section is expanded at a time.
+///
+/// ```no_run
+/// use yew_utils::components::accordion::{Accordion, AccordionProps, Section};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// comp_with::<Accordion>(AccordionProps {
+///     sections: vec![
+///         Section::new("Shipping", p().text("ships within 2 days")),
+///         Section::new("Returns", p().text("30 days")),
+///     ],
+///     initial: vec![0],
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct Accordion {
+    id: String,
+    expanded: Vec<usize>,
+    /// Header to focus after rendering, after keyboard navigation.
+    focus: Option<usize>,
+}
+
+pub enum Msg {
+    Toggled(usize),
+    Navigated(usize),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct AccordionProps {
+    pub sections: Vec<Section>,
+    /// Allow more than one expanded section.
+    #[prop_or_default]
+    pub multiple: bool,
+    /// The expanded sections in controlled mode.
+    #[prop_or_default]
+    pub expanded: Option<Vec<usize>>,
+    /// The initially expanded sections in uncontrolled mode.
+    #[prop_or_default]
+    pub initial: Vec<usize>,
+    /// Receives the expanded sections after a header was clicked.
+    #[prop_or_default]
+    pub on_change: Callback<Vec<usize>>,
+    /// Duration of the expand and collapse transition.
+    #[prop_or(200)]
+    pub duration_ms: u32,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for AccordionProps {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            multiple: false,
+            expanded: None,
+            initial: Vec::new(),
+            on_change: Callback::noop(),
+            duration_ms: 200,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl Accordion {
+    fn next_id() -> String {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        format!("yew-accordion-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn current<'a>(&'a self, props: &'a AccordionProps) -> &'a [usize] {
+        props.expanded.as_deref().unwrap_or(&self.expanded)
+    }
+
+    fn header_id(&self, i: usize) -> String {
+        format!("{}-header-{i}", self.id)
+    }
+
+    fn panel_id(&self, i: usize) -> String {
+        format!("{}-panel-{i}", self.id)
+    }
+}
+
+impl Component for Accordion {
+    type Message = Msg;
+    type Properties = AccordionProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            id: Self::next_id(),
+            expanded: ctx.props().initial.clone(),
+            focus: None,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let expanded = self.current(props);
+        let disabled = props
+            .sections
+            .iter()
+            .map(|s| s.disabled)
+            .collect::<Vec<_>>();
+
+        let sections = props.sections.iter().enumerate().map(|(i, section)| {
+            let open = expanded.contains(&i);
+            let disabled = disabled.clone();
+            let mut header = button()
+                .attr("type", "button")
+                .id(self.header_id(i))
+                .class("accordion-header")
+                .attr("aria-expanded", open.to_string())
+                .attr("aria-controls", self.panel_id(i))
+                .text(section.title.clone())
+                .onkeydown(link.batch_callback(move |e: KeyboardEvent| {
+                    let next = navigate(&disabled, i, &e.key())?;
+                    e.prevent_default();
+                    Some(Msg::Navigated(next))
+                }));
+            header = match section.disabled {
+                true => header.attr("aria-disabled", "true"),
+                false => header.onclick(link.callback(move |_| Msg::Toggled(i))),
+            };
+
+            // visibility switches at the end of the transition when
+            // collapsing, so collapsed content can't be focused
+            let duration = props.duration_ms;
+            let style = format!(
+                "display: grid; grid-template-rows: {}fr; visibility: {}; \
+                 transition: grid-template-rows {duration}ms ease, visibility {duration}ms",
+                if open { 1 } else { 0 },
+                if open { "visible" } else { "hidden" },
+            );
+            let panel = div()
+                .id(self.panel_id(i))
+                .classes(classes!(
+                    "accordion-panel",
+                    open.then_some("accordion-panel--expanded")
+                ))
+                .attr("role", "region")
+                .attr("aria-labelledby", self.header_id(i))
+                .attr("style", style)
+                .append(
+                    div()
+                        .class("accordion-content")
+                        .attr("style", "overflow: hidden; min-height: 0")
+                        .append(section.content.clone()),
+                );
+
+            div()
+                .key(i)
+                .classes(classes!(
+                    "accordion-section",
+                    open.then_some("accordion-section--expanded")
+                ))
+                .append(h3().class("accordion-title").append(header))
+                .append(panel)
+        });
+
+        div()
+            .classes(classes!("accordion", props.class.clone()))
+            .append_all(sections)
+            .to_vnode()
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Toggled(i) => {
+                let expanded = toggle(self.current(props), i, props.multiple);
+                if props.expanded.is_none() {
+                    self.expanded = expanded.clone();
+                }
+                props.on_change.emit(expanded);
+            }
+            Msg::Navigated(i) => self.focus = Some(i),
+        }
+        true
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        let Some(i) = self.focus.take() else {
+            return;
+        };
+        let id = self.header_id(i);
+        let header = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&id));
+        if let Some(header) = header.and_then(|header| header.dyn_into::<HtmlElement>().ok()) {
+            let _ = header.focus();
+        }
+    }
+}
@@ -0,0 +1,693 @@
+//! Calendars for picking a date or a range of dates.
+//!
+//! The calendar shows the weeks of a month as a `role=grid` table, with
+//! buttons to go to the previous or next month and year. Arrow keys move
+//! between days, Home and End to the start and end of the week, Page Up and
+//! Page Down between months. Month and weekday names are formatted for the
+//! locale of the browser or the `locale` prop, which also decides on which day
+//! weeks start.
+//!
+//! The calendar math works without a browser:
+//!
+//! ```
+//! use yew_utils::components::date_picker::{month_grid, week_start};
+//! use yew_utils::date::{Date, Weekday};
+//!
+//! assert_eq!(week_start("en-US"), Weekday::Sunday);
+//! assert_eq!(week_start("de-DE"), Weekday::Monday);
+//!
+//! let weeks = month_grid(Date::new(2024, 2, 1).unwrap(), Weekday::Monday);
+//! assert_eq!(weeks.len(), 6);
+//! assert_eq!(weeks[0][0], Date::new(2024, 1, 29).unwrap());
+//! assert_eq!(weeks[5][6], Date::new(2024, 3, 10).unwrap());
+//!
+//! // April 2024 starts on a Monday, September 2024 on a Sunday
+//! let weeks = month_grid(Date::new(2024, 4, 15).unwrap(), Weekday::Monday);
+//! assert_eq!(weeks[0][0], Date::new(2024, 4, 1).unwrap());
+//! assert_eq!(weeks[5][6], Date::new(2024, 5, 12).unwrap());
+//! let weeks = month_grid(Date::new(2024, 9, 1).unwrap(), Weekday::Sunday);
+//! assert_eq!(weeks[0][0], Date::new(2024, 9, 1).unwrap());
+//! let weeks = month_grid(Date::new(2024, 9, 1).unwrap(), Weekday::Monday);
+//! assert_eq!(weeks[0][0], Date::new(2024, 8, 26).unwrap());
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::date::{Date, Weekday};
+use crate::vdom::{abbr, button, div, h2, table, tbody, td, th, thead, tr};
+
+/// The first day of the week in `locale`, a BCP 47 language tag like `en-US`.
+/// Locales without a region use the region where the language is most
+/// commonly spoken.
+///
+/// ```
+/// use yew_utils::components::date_picker::week_start;
+/// use yew_utils::date::Weekday;
+///
+/// assert_eq!(week_start("en"), Weekday::Sunday);
+/// assert_eq!(week_start("en-GB"), Weekday::Monday);
+/// assert_eq!(week_start("ar-EG"), Weekday::Saturday);
+/// assert_eq!(week_start("fr"), Weekday::Monday);
+/// ```
+pub fn week_start(locale: &str) -> Weekday {
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_lowercase();
+    let region = subtags
+        .find(|subtag| subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|region| region.to_uppercase());
+    let region = region.as_deref().unwrap_or(match language.as_str() {
+        "en" => "US",
+        "ja" => "JP",
+        "ko" => "KR",
+        "he" => "IL",
+        "pt" => "BR",
+        "hi" => "IN",
+        "ar" => "EG",
+        "fa" => "IR",
+        _ => "",
+    });
+    match region {
+        "AG" | "AS" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CO" | "DM" | "DO"
+        | "ET" | "GT" | "GU" | "HK" | "HN" | "ID" | "IL" | "IN" | "JM" | "JP" | "KE" | "KH"
+        | "KR" | "LA" | "MH" | "MM" | "MO" | "MT" | "MX" | "MZ" | "NI" | "NP" | "PA" | "PE"
+        | "PH" | "PK" | "PR" | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT" | "TW" | "UM"
+        | "US" | "VE" | "VI" | "WS" | "YE" | "ZA" | "ZW" => Weekday::Sunday,
+        "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM"
+        | "QA" | "SD" | "SY" => Weekday::Saturday,
+        _ => Weekday::Monday,
+    }
+}
+
+/// The six weeks shown for the month of `month`, starting on `week_start`.
+/// Days of the previous and next month fill the first and last weeks.
+pub fn month_grid(month: Date, week_start: Weekday) -> Vec<[Date; 7]> {
+    let first = month.first_of_month();
+    let start = first.add_days(-(first.weekday().days_since(week_start) as i64));
+    (0..6)
+        .map(|week| std::array::from_fn(|day| start.add_days(week * 7 + day as i64)))
+        .collect()
+}
+
+/// The day that `key` moves to from `date`.
+///
+/// ```
+/// use yew_utils::components::date_picker::navigate;
+/// use yew_utils::date::{Date, Weekday};
+///
+/// let date = Date::new(2024, 1, 31).unwrap(); // a Wednesday
+/// assert_eq!(navigate(date, "ArrowDown", Weekday::Monday), Date::new(2024, 2, 7));
+/// assert_eq!(navigate(date, "Home", Weekday::Monday), Date::new(2024, 1, 29));
+/// assert_eq!(navigate(date, "End", Weekday::Sunday), Date::new(2024, 2, 3));
+/// assert_eq!(navigate(date, "PageDown", Weekday::Monday), Date::new(2024, 2, 29));
+/// assert_eq!(navigate(date, "Enter", Weekday::Monday), None);
+/// ```
+pub fn navigate(date: Date, key: &str, week_start: Weekday) -> Option<Date> {
+    let days_since_start = date.weekday().days_since(week_start) as i64;
+    let next = match key {
+        "ArrowLeft" => date.add_days(-1),
+        "ArrowRight" => date.add_days(1),
+        "ArrowUp" => date.add_days(-7),
+        "ArrowDown" => date.add_days(7),
+        "Home" => date.add_days(-days_since_start),
+        "End" => date.add_days(6 - days_since_start),
+        "PageUp" => date.add_months(-1),
+        "PageDown" => date.add_months(1),
+        _ => return None,
+    };
+    Some(next)
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+// formatting with Intl
+
+/// `locale`, or the locale of the browser if not set.
+fn resolve_locale(locale: &Option<AttrValue>) -> String {
+    if let Some(locale) = locale {
+        return locale.to_string();
+    }
+    let format = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &js_sys::Object::new());
+    js_sys::Reflect::get(&format.resolved_options(), &"locale".into())
+        .ok()
+        .and_then(|locale| locale.as_string())
+        .unwrap_or_else(|| "en-US".to_string())
+}
+
+/// The `Intl.DateTimeFormat`s of a calendar, built once per locale.
+struct Formats {
+    locale: String,
+    title: js_sys::Function,
+    weekday_long: js_sys::Function,
+    weekday_short: js_sys::Function,
+    day: js_sys::Function,
+}
+
+impl Formats {
+    fn new(locale: String) -> Self {
+        let format = |options: &[(&str, &str)]| {
+            let js_options = js_sys::Object::new();
+            // dates are passed as midnight UTC, see `format_date`
+            for (key, value) in options.iter().chain(&[("timeZone", "UTC")]) {
+                let _ = js_sys::Reflect::set(&js_options, &(*key).into(), &(*value).into());
+            }
+            let locales = js_sys::Array::of1(&locale.as_str().into());
+            js_sys::Intl::DateTimeFormat::new(&locales, &js_options).format()
+        };
+        Self {
+            title: format(&[("month", "long"), ("year", "numeric")]),
+            weekday_long: format(&[("weekday", "long")]),
+            weekday_short: format(&[("weekday", "short")]),
+            day: format(&[("dateStyle", "full")]),
+            locale,
+        }
+    }
+}
+
+/// Formats `date` with the `format` function of an `Intl.DateTimeFormat`.
+fn format_date(format: &js_sys::Function, date: Date) -> String {
+    // built from the time since the epoch, the `Date` constructor would take
+    // the years 0 to 99 as 1900 to 1999
+    let time = date.days_since_epoch() as f64 * 86_400_000.0;
+    format
+        .call1(&JsValue::NULL, &js_sys::Date::new(&time.into()))
+        .ok()
+        .and_then(|formatted| formatted.as_string())
+        .unwrap_or_else(|| date.to_string())
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+pub enum Msg {
+    Picked(Date),
+    Hovered(Option<Date>),
+    Navigated(Date),
+    MonthChanged(i32),
+}
+
+/// How a day is shown.
+#[derive(Default)]
+struct DayState {
+    selected: bool,
+    range_start: bool,
+    range_end: bool,
+    in_range: bool,
+}
+
+/// Props of both pickers that are about the calendar.
+struct Limits<'a> {
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: &'a Option<Callback<Date, bool>>,
+}
+
+impl Limits<'_> {
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    fn disabled(&self, date: Date) -> bool {
+        self.min.is_some_and(|min| date < min)
+            || self.max.is_some_and(|max| date > max)
+            || self.is_disabled.as_ref().is_some_and(|f| f.emit(date))
+    }
+}
+
+/// State and markup of the calendar that both pickers show.
+struct Calendar {
+    id: String,
+    formats: Formats,
+    /// The first day of the shown month.
+    month: Date,
+    /// The day that can be focused with Tab.
+    focused: Date,
+    /// Focus the focused day after rendering, after keyboard navigation.
+    focus: bool,
+}
+
+impl Calendar {
+    fn new(locale: &Option<AttrValue>, initial: Date) -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        Self {
+            id: format!(
+                "yew-date-picker-{}",
+                NEXT_ID.fetch_add(1, Ordering::Relaxed)
+            ),
+            formats: Formats::new(resolve_locale(locale)),
+            month: initial.first_of_month(),
+            focused: initial,
+            focus: false,
+        }
+    }
+
+    fn locale(&self) -> &str {
+        &self.formats.locale
+    }
+
+    /// Rebuilds the formats if the resolved locale changed.
+    fn set_locale(&mut self, locale: &Option<AttrValue>) {
+        let locale = resolve_locale(locale);
+        if locale != self.formats.locale {
+            self.formats = Formats::new(locale);
+        }
+    }
+
+    fn day_id(&self, date: Date) -> String {
+        format!("{}-{date}", self.id)
+    }
+
+    fn show(&mut self, date: Date) {
+        self.focused = date;
+        self.month = date.first_of_month();
+    }
+
+    fn update(&mut self, limits: &Limits, msg: &Msg) {
+        match msg {
+            Msg::Navigated(date) => {
+                self.show(limits.clamp(*date));
+                self.focus = true;
+            }
+            Msg::MonthChanged(months) => {
+                self.month = self.month.add_months(*months);
+                self.focused = limits.clamp(self.focused.add_months(*months));
+            }
+            Msg::Picked(date) => self.focused = *date,
+            Msg::Hovered(_) => {}
+        }
+    }
+
+    fn view(
+        &self,
+        link: &yew::html::Scope<impl Component<Message = Msg>>,
+        limits: &Limits,
+        week_start: Weekday,
+        day_state: impl Fn(Date) -> DayState,
+        class: Classes,
+    ) -> Html {
+        let nav = |label: &'static str, text: &'static str, months: i32| {
+            button()
+                .attr("type", "button")
+                .class("date-picker-nav")
+                .attr("aria-label", label)
+                .text(text)
+                .onclick(link.callback(move |_| Msg::MonthChanged(months)))
+        };
+        let title_id = format!("{}-title", self.id);
+        let header = div()
+            .class("date-picker-header")
+            .append(nav("Previous year", "«", -12))
+            .append(nav("Previous month", "‹", -1))
+            .append(
+                h2().id(title_id.clone())
+                    .class("date-picker-title")
+                    .attr("aria-live", "polite")
+                    .text(format_date(&self.formats.title, self.month)),
+            )
+            .append(nav("Next month", "›", 1))
+            .append(nav("Next year", "»", 12));
+
+        let weeks = month_grid(self.month, week_start);
+        let weekdays = weeks[0].iter().map(|date| {
+            th().attr("scope", "col").append(
+                abbr()
+                    .attr("title", format_date(&self.formats.weekday_long, *date))
+                    .text(format_date(&self.formats.weekday_short, *date)),
+            )
+        });
+
+        let today = Date::today();
+        let rows = weeks.iter().map(|week| {
+            let days = week.iter().map(|&date| {
+                let state = day_state(date);
+                let disabled = limits.disabled(date);
+                let outside = date.first_of_month() != self.month;
+                let mut day = button()
+                    .attr("type", "button")
+                    .id(self.day_id(date))
+                    .classes(classes!(
+                        "date-picker-day",
+                        outside.then_some("date-picker-day--outside"),
+                        (date == today).then_some("date-picker-day--today"),
+                        state.selected.then_some("date-picker-day--selected"),
+                        state.in_range.then_some("date-picker-day--in-range"),
+                        state.range_start.then_some("date-picker-day--range-start"),
+                        state.range_end.then_some("date-picker-day--range-end"),
+                    ))
+                    .attr("tabindex", if date == self.focused { "0" } else { "-1" })
+                    .attr("aria-label", format_date(&self.formats.day, date))
+                    .attr("data-date", date.to_string())
+                    .text(date.day().to_string())
+                    .onmouseenter(link.callback(move |_| Msg::Hovered(Some(date))));
+                day = match disabled {
+                    true => day.attr("aria-disabled", "true"),
+                    false => day.onclick(link.callback(move |_| Msg::Picked(date))),
+                };
+                td().attr("role", "gridcell")
+                    .attr("aria-selected", state.selected.to_string())
+                    .append(day)
+            });
+            tr().append_all(days)
+        });
+
+        let focused = self.focused;
+        let grid = table()
+            .class("date-picker-grid")
+            .attr("role", "grid")
+            .attr("aria-labelledby", title_id)
+            .onkeydown(link.batch_callback(move |e: KeyboardEvent| {
+                let next = navigate(focused, &e.key(), week_start)?;
+                e.prevent_default();
+                Some(Msg::Navigated(next))
+            }))
+            .onmouseleave(link.callback(|_| Msg::Hovered(None)))
+            .append(thead().append(tr().append_all(weekdays)))
+            .append(tbody().append_all(rows));
+
+        div()
+            .classes(classes!("date-picker", class))
+            .append(header)
+            .append(grid)
+            .to_vnode()
+    }
+
+    fn rendered(&mut self) {
+        if !std::mem::take(&mut self.focus) {
+            return;
+        }
+        let id = self.day_id(self.focused);
+        let day = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&id));
+        if let Some(day) = day.and_then(|day| day.dyn_into::<HtmlElement>().ok()) {
+            let _ = day.focus();
+        }
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Calendar for picking a single date. The date is controlled by the parent,
+/// which receives picked dates with `on_change`.
+///
+/// ```no_run
+/// use yew_utils::components::date_picker::{DatePicker, DatePickerProps};
+/// use yew_utils::date::{Date, Weekday};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let date = use_state(|| None);
+/// comp_with::<DatePicker>(DatePickerProps {
+///     value: *date,
+///     min: Some(Date::today()),
+///     // no weekends
+///     is_disabled: Some(Callback::from(|date: Date| {
+///         matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+///     })),
+///     on_change: Callback::from(move |picked| date.set(Some(picked))),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct DatePicker {
+    calendar: Calendar,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DatePickerProps {
+    #[prop_or_default]
+    pub value: Option<Date>,
+    #[prop_or_default]
+    pub on_change: Callback<Date>,
+    #[prop_or_default]
+    pub min: Option<Date>,
+    #[prop_or_default]
+    pub max: Option<Date>,
+    /// Dates that can't be picked, in addition to those before `min` and
+    /// after `max`.
+    #[prop_or_default]
+    pub is_disabled: Option<Callback<Date, bool>>,
+    /// BCP 47 language tag, the locale of the browser if not set.
+    #[prop_or_default]
+    pub locale: Option<AttrValue>,
+    /// Overrides the first day of the week of the locale.
+    #[prop_or_default]
+    pub week_start: Option<Weekday>,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for DatePickerProps {
+    fn default() -> Self {
+        Self {
+            value: None,
+            on_change: Callback::noop(),
+            min: None,
+            max: None,
+            is_disabled: None,
+            locale: None,
+            week_start: None,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl DatePickerProps {
+    fn limits(&self) -> Limits<'_> {
+        Limits {
+            min: self.min,
+            max: self.max,
+            is_disabled: &self.is_disabled,
+        }
+    }
+}
+
+impl Component for DatePicker {
+    type Message = Msg;
+    type Properties = DatePickerProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let initial = props
+            .value
+            .unwrap_or_else(|| props.limits().clamp(Date::today()));
+        Self {
+            calendar: Calendar::new(&props.locale, initial),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let week_start = props
+            .week_start
+            .unwrap_or_else(|| week_start(self.calendar.locale()));
+        self.calendar.view(
+            ctx.link(),
+            &props.limits(),
+            week_start,
+            |date| DayState {
+                selected: props.value == Some(date),
+                ..Default::default()
+            },
+            props.class.clone(),
+        )
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        self.calendar.update(&props.limits(), &msg);
+        match msg {
+            Msg::Picked(date) => props.on_change.emit(date),
+            Msg::Hovered(_) => return false,
+            Msg::Navigated(_) | Msg::MonthChanged(_) => {}
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.locale != old_props.locale {
+            self.calendar.set_locale(&props.locale);
+        }
+        if let Some(value) = props.value.filter(|_| props.value != old_props.value) {
+            self.calendar.show(value);
+        }
+        true
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        self.calendar.rendered();
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Calendar for picking a range of dates: the first click picks one end of
+/// the range, the second click the other one. `on_change` receives the range
+/// with the earlier date first.
+///
+/// ```no_run
+/// use yew_utils::components::date_picker::{DateRangePicker, DateRangePickerProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let range = use_state(|| None);
+/// comp_with::<DateRangePicker>(DateRangePickerProps {
+///     value: *range,
+///     locale: Some("en-GB".into()),
+///     on_change: Callback::from(move |picked| range.set(Some(picked))),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct DateRangePicker {
+    calendar: Calendar,
+    /// The end of the range that was picked first.
+    anchor: Option<Date>,
+    hovered: Option<Date>,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DateRangePickerProps {
+    #[prop_or_default]
+    pub value: Option<(Date, Date)>,
+    #[prop_or_default]
+    pub on_change: Callback<(Date, Date)>,
+    #[prop_or_default]
+    pub min: Option<Date>,
+    #[prop_or_default]
+    pub max: Option<Date>,
+    /// Dates that can't be picked, in addition to those before `min` and
+    /// after `max`.
+    #[prop_or_default]
+    pub is_disabled: Option<Callback<Date, bool>>,
+    /// BCP 47 language tag, the locale of the browser if not set.
+    #[prop_or_default]
+    pub locale: Option<AttrValue>,
+    /// Overrides the first day of the week of the locale.
+    #[prop_or_default]
+    pub week_start: Option<Weekday>,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for DateRangePickerProps {
+    fn default() -> Self {
+        Self {
+            value: None,
+            on_change: Callback::noop(),
+            min: None,
+            max: None,
+            is_disabled: None,
+            locale: None,
+            week_start: None,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl DateRangePickerProps {
+    fn limits(&self) -> Limits<'_> {
+        Limits {
+            min: self.min,
+            max: self.max,
+            is_disabled: &self.is_disabled,
+        }
+    }
+}
+
+impl DateRangePicker {
+    /// The picked range, or while picking the range from the anchor to the
+    /// hovered or focused day.
+    fn range(&self, props: &DateRangePickerProps) -> Option<(Date, Date)> {
+        match self.anchor {
+            Some(anchor) => {
+                let other = self.hovered.unwrap_or(self.calendar.focused);
+                Some((anchor.min(other), anchor.max(other)))
+            }
+            None => props.value,
+        }
+    }
+}
+
+impl Component for DateRangePicker {
+    type Message = Msg;
+    type Properties = DateRangePickerProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let initial = props
+            .value
+            .map(|(start, _)| start)
+            .unwrap_or_else(|| props.limits().clamp(Date::today()));
+        Self {
+            calendar: Calendar::new(&props.locale, initial),
+            anchor: None,
+            hovered: None,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let week_start = props
+            .week_start
+            .unwrap_or_else(|| week_start(self.calendar.locale()));
+        let range = self.range(props);
+        self.calendar.view(
+            ctx.link(),
+            &props.limits(),
+            week_start,
+            |date| match range {
+                Some((start, end)) => DayState {
+                    selected: date == start || date == end,
+                    range_start: date == start,
+                    range_end: date == end,
+                    in_range: start <= date && date <= end,
+                },
+                None => DayState::default(),
+            },
+            classes!("date-picker--range", props.class.clone()),
+        )
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        self.calendar.update(&props.limits(), &msg);
+        match msg {
+            Msg::Picked(date) => match self.anchor.take() {
+                Some(anchor) => props.on_change.emit((anchor.min(date), anchor.max(date))),
+                None => self.anchor = Some(date),
+            },
+            Msg::Hovered(date) => {
+                self.hovered = date;
+                return self.anchor.is_some();
+            }
+            Msg::Navigated(_) => self.hovered = None,
+            Msg::MonthChanged(_) => {}
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.locale != old_props.locale {
+            self.calendar.set_locale(&props.locale);
+        }
+        if let Some((start, _)) = props.value.filter(|_| props.value != old_props.value) {
+            self.anchor = None;
+            self.calendar.show(start);
+        }
+        true
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        self.calendar.rendered();
+    }
+}
@@ -0,0 +1,73 @@
+//! Form fields styled with [mui-css forms](https://www.muicss.com/docs/v1/css-js/forms),
+//! see [TextField].
+
+use yew::prelude::*;
+
+use crate::vdom::{div, fieldset, label, legend, Tag};
+
+pub(super) use super::fields_shared::Layout;
+pub use super::fields_shared::{
+    CheckboxField, CheckboxFieldProps, DateField, DateFieldProps, Msg, NumberField,
+    NumberFieldProps, RadioGroup, RadioGroupProps, Switch, TextArea, TextAreaProps, TextField,
+    TextFieldProps,
+};
+
+pub(super) fn field(layout: Layout, id: &str, text: &AttrValue, control: Html) -> Tag {
+    match layout {
+        // mui-css expects the label after the control
+        Layout::Text => div()
+            .class("mui-textfield")
+            .append(control)
+            .append(label().attr("for", id.to_string()).text(text.clone())),
+        Layout::Check => div().class("mui-checkbox").append(
+            label()
+                .append(control)
+                .append(crate::vdom::text(format!(" {text}"))),
+        ),
+        Layout::Group => fieldset()
+            .append(legend().text(text.clone()))
+            .append(control),
+    }
+}
+
+pub(super) fn field_classes(_invalid: bool, _disabled: bool) -> Classes {
+    classes!("field")
+}
+
+pub(super) fn control_classes(invalid: bool) -> Classes {
+    classes!(invalid.then_some(classes!("mui--is-invalid", "mui--is-touched")))
+}
+
+/// A radio button with its label.
+pub(super) fn choice(control: Html, text: &AttrValue) -> Html {
+    div()
+        .class("mui-radio")
+        .append(
+            label()
+                .append(control)
+                .append(crate::vdom::text(format!(" {text}"))),
+        )
+        .to_vnode()
+}
+
+pub(super) fn help(id: String, text: &AttrValue) -> Tag {
+    div()
+        .id(id)
+        .classes(classes!(
+            "field-help",
+            "mui--text-caption",
+            "mui--text-dark-secondary"
+        ))
+        .text(text.clone())
+}
+
+pub(super) fn error(id: String, text: &AttrValue) -> Tag {
+    div()
+        .id(id)
+        .classes(classes!(
+            "field-error",
+            "mui--text-caption",
+            "mui--text-danger"
+        ))
+        .text(text.clone())
+}
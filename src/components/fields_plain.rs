@@ -0,0 +1,57 @@
+//! Form fields with plain markup, see [TextField].
+//!
+//! Fields are `div.field` elements, with `field--invalid` and `field--disabled`
+//! modifiers, containing the label, the control, a `.field-help` and a
+//! `.field-error`.
+
+use yew::prelude::*;
+
+use crate::vdom::{div, fieldset, label, legend, span, Tag};
+
+pub(super) use super::fields_shared::Layout;
+pub use super::fields_shared::{
+    CheckboxField, CheckboxFieldProps, DateField, DateFieldProps, Msg, NumberField,
+    NumberFieldProps, RadioGroup, RadioGroupProps, Switch, TextArea, TextAreaProps, TextField,
+    TextFieldProps,
+};
+
+pub(super) fn field(layout: Layout, id: &str, text: &AttrValue, control: Html) -> Tag {
+    match layout {
+        Layout::Text => div()
+            .append(label().attr("for", id.to_string()).text(text.clone()))
+            .append(control),
+        Layout::Check => div().append(choice(control, text)),
+        Layout::Group => fieldset()
+            .append(legend().text(text.clone()))
+            .append(control),
+    }
+}
+
+pub(super) fn field_classes(invalid: bool, disabled: bool) -> Classes {
+    classes!(
+        "field",
+        invalid.then_some("field--invalid"),
+        disabled.then_some("field--disabled")
+    )
+}
+
+pub(super) fn control_classes(_invalid: bool) -> Classes {
+    Classes::new()
+}
+
+/// A checkbox or radio button with its label.
+pub(super) fn choice(control: Html, text: &AttrValue) -> Html {
+    label()
+        .class("field-choice")
+        .append(control)
+        .append(span().text(text.clone()))
+        .to_vnode()
+}
+
+pub(super) fn help(id: String, text: &AttrValue) -> Tag {
+    div().id(id).class("field-help").text(text.clone())
+}
+
+pub(super) fn error(id: String, text: &AttrValue) -> Tag {
+    div().id(id).class("field-error").text(text.clone())
+}
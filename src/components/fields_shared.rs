@@ -0,0 +1,833 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::fields::{choice, control_classes, error, field, field_classes, help};
+use crate::date::Date;
+use crate::form::FieldBinding;
+use crate::vdom::{checkbox, input, tag, textarea, Tag, TagType};
+
+/// How the label and control of a field are arranged.
+pub enum Layout {
+    /// Label and then a text-like control.
+    Text,
+    /// A checkbox with its label.
+    Check,
+    /// A group of controls with a common label.
+    Group,
+}
+
+fn next_id() -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    format!("yew-field-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// What all fields have in common.
+struct Common<'a> {
+    id: &'a str,
+    label: &'a AttrValue,
+    help: Option<&'a AttrValue>,
+    error: Option<&'a AttrValue>,
+    disabled: bool,
+    class: &'a Classes,
+}
+
+impl Common<'_> {
+    fn help_id(&self) -> String {
+        format!("{}-help", self.id)
+    }
+
+    fn error_id(&self) -> String {
+        format!("{}-error", self.id)
+    }
+
+    fn described_by(&self) -> Option<String> {
+        let ids = [
+            self.help.map(|_| self.help_id()),
+            self.error.map(|_| self.error_id()),
+        ];
+        let ids = ids.into_iter().flatten().collect::<Vec<_>>();
+        (!ids.is_empty()).then(|| ids.join(" "))
+    }
+
+    /// Sets the id, ARIA attributes and disabled state of the control.
+    fn control<T: TagType>(&self, control: Tag<T>) -> Tag<T> {
+        let mut control = control
+            .id(self.id.to_string())
+            .classes(control_classes(self.error.is_some()))
+            .attr("aria-invalid", self.error.is_some().to_string());
+        if let Some(ids) = self.described_by() {
+            control = control.attr("aria-describedby", ids);
+        }
+        if self.disabled {
+            control = control.attr("disabled", "disabled");
+        }
+        control
+    }
+
+    fn view(&self, layout: Layout, control: Html) -> Html {
+        let mut wrapper = field(layout, self.id, self.label, control).classes(classes!(
+            field_classes(self.error.is_some(), self.disabled),
+            self.class.clone()
+        ));
+        if let Some(text) = self.help {
+            wrapper = wrapper.append(help(self.help_id(), text));
+        }
+        if let Some(text) = self.error {
+            wrapper = wrapper.append(error(self.error_id(), text).attr("role", "alert"));
+        }
+        wrapper.to_vnode()
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Text input with a label, help text and error message.
+///
+/// ```no_run
+/// use yew_utils::components::fields::{TextField, TextFieldProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let name = use_state(String::new);
+/// comp_with::<TextField>(TextFieldProps {
+///     label: "Name".into(),
+///     value: (*name).clone().into(),
+///     on_change: Callback::from(move |value| name.set(value)),
+///     help: Some("As shown on your profile".into()),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+///
+/// Bound to a field of a [Form](crate::form::Form), the value, error and
+/// callbacks come from the form:
+/// `comp_with::<TextField>(TextFieldProps::bound("Email", form.field("email")))`.
+pub struct TextField {
+    id: String,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TextFieldProps {
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub value: AttrValue,
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+    #[prop_or_default]
+    pub on_blur: Callback<()>,
+    /// The `type` of the input, e.g. `email` or `password`.
+    #[prop_or(AttrValue::Static("text"))]
+    pub input_type: AttrValue,
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for TextFieldProps {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            value: AttrValue::default(),
+            on_change: Callback::noop(),
+            on_blur: Callback::noop(),
+            input_type: AttrValue::Static("text"),
+            name: None,
+            placeholder: None,
+            help: None,
+            error: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl TextFieldProps {
+    /// Props for a field of a [Form](crate::form::Form).
+    pub fn bound(label: impl Into<AttrValue>, field: FieldBinding) -> Self {
+        Self {
+            label: label.into(),
+            value: field.value,
+            on_change: field.on_input,
+            on_blur: field.on_blur,
+            name: Some(field.name),
+            error: field.error,
+            ..Default::default()
+        }
+    }
+}
+
+impl Component for TextField {
+    type Message = ();
+    type Properties = TextFieldProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { id: next_id() }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let common = Common {
+            id: &self.id,
+            label: &props.label,
+            help: props.help.as_ref(),
+            error: props.error.as_ref(),
+            disabled: props.disabled,
+            class: &props.class,
+        };
+
+        let on_change = props.on_change.clone();
+        let on_blur = props.on_blur.clone();
+        let mut control = input()
+            .attr("type", props.input_type.clone())
+            .value(props.value.clone())
+            .oninput(move |e: InputEvent| {
+                on_change.emit(e.target_unchecked_into::<HtmlInputElement>().value())
+            })
+            .onblur(move |_| on_blur.emit(()));
+        if let Some(name) = &props.name {
+            control = control.attr("name", name.clone());
+        }
+        if let Some(placeholder) = &props.placeholder {
+            control = control.attr("placeholder", placeholder.clone());
+        }
+        common.view(Layout::Text, common.control(control).to_vnode())
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Multi-line text input, see [TextField].
+pub struct TextArea {
+    id: String,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TextAreaProps {
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub value: AttrValue,
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+    #[prop_or_default]
+    pub on_blur: Callback<()>,
+    #[prop_or_default]
+    pub rows: Option<u32>,
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for TextAreaProps {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            value: AttrValue::default(),
+            on_change: Callback::noop(),
+            on_blur: Callback::noop(),
+            rows: None,
+            name: None,
+            placeholder: None,
+            help: None,
+            error: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl TextAreaProps {
+    /// Props for a field of a [Form](crate::form::Form).
+    pub fn bound(label: impl Into<AttrValue>, field: FieldBinding) -> Self {
+        Self {
+            label: label.into(),
+            value: field.value,
+            on_change: field.on_input,
+            on_blur: field.on_blur,
+            name: Some(field.name),
+            error: field.error,
+            ..Default::default()
+        }
+    }
+}
+
+impl Component for TextArea {
+    type Message = ();
+    type Properties = TextAreaProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { id: next_id() }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let common = Common {
+            id: &self.id,
+            label: &props.label,
+            help: props.help.as_ref(),
+            error: props.error.as_ref(),
+            disabled: props.disabled,
+            class: &props.class,
+        };
+
+        let on_change = props.on_change.clone();
+        let on_blur = props.on_blur.clone();
+        let mut control = textarea()
+            .value(props.value.clone())
+            .oninput(move |e: InputEvent| {
+                on_change.emit(
+                    e.target_unchecked_into::<web_sys::HtmlTextAreaElement>()
+                        .value(),
+                )
+            })
+            .onblur(move |_| on_blur.emit(()));
+        if let Some(rows) = props.rows {
+            control = control.attr("rows", rows.to_string());
+        }
+        if let Some(name) = &props.name {
+            control = control.attr("name", name.clone());
+        }
+        if let Some(placeholder) = &props.placeholder {
+            control = control.attr("placeholder", placeholder.clone());
+        }
+        common.view(Layout::Text, common.control(control).to_vnode())
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// The value of an input parsed as `V` and checked against `min` and `max`.
+/// An empty input is `None`.
+fn parse_input<V>(input: &str, min: Option<&V>, max: Option<&V>) -> Result<Option<V>, String>
+where
+    V: FromStr + Display + PartialOrd,
+{
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let value = input
+        .parse::<V>()
+        .map_err(|_| format!("not valid: {input}"))?;
+    match (min, max) {
+        (Some(min), _) if value < *min => Err(format!("at least {min}")),
+        (_, Some(max)) if value > *max => Err(format!("at most {max}")),
+        _ => Ok(Some(value)),
+    }
+}
+
+pub enum Msg {
+    Input(String),
+    /// The browser can't make sense of the input, its value is empty then.
+    BadInput,
+}
+
+fn input_msg(e: InputEvent) -> Msg {
+    let input = e.target_unchecked_into::<HtmlInputElement>();
+    match input.validity().bad_input() {
+        true => Msg::BadInput,
+        false => Msg::Input(input.value()),
+    }
+}
+
+/// The input of a [NumberField] or [DateField] while it is invalid.
+#[derive(Default)]
+struct Draft {
+    input: String,
+    error: String,
+}
+
+/// Number input that emits parsed numbers, e.g. `f64` or `i64`. Input that
+/// doesn't parse or is out of range is shown with an error and not emitted.
+///
+/// ```no_run
+/// use yew_utils::components::fields::{NumberField, NumberFieldProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let quantity = use_state(|| Some(1_i64));
+/// comp_with::<NumberField<i64>>(NumberFieldProps {
+///     label: "Quantity".into(),
+///     value: *quantity,
+///     min: Some(1),
+///     max: Some(99),
+///     on_change: Callback::from(move |value| quantity.set(value)),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct NumberField<N> {
+    id: String,
+    draft: Option<Draft>,
+    _number: PhantomData<N>,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct NumberFieldProps<N: PartialEq> {
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub value: Option<N>,
+    /// Receives valid numbers, or `None` if the input was cleared.
+    #[prop_or_default]
+    pub on_change: Callback<Option<N>>,
+    #[prop_or_default]
+    pub min: Option<N>,
+    #[prop_or_default]
+    pub max: Option<N>,
+    /// Any step if not set.
+    #[prop_or_default]
+    pub step: Option<N>,
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl<N: PartialEq> Default for NumberFieldProps<N> {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            value: None,
+            on_change: Callback::noop(),
+            min: None,
+            max: None,
+            step: None,
+            help: None,
+            error: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl<N> Component for NumberField<N>
+where
+    N: FromStr + Display + PartialOrd + Clone + 'static,
+{
+    type Message = Msg;
+    type Properties = NumberFieldProps<N>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            id: next_id(),
+            draft: None,
+            _number: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let draft_error = self.draft.as_ref().map(|draft| draft.error.clone().into());
+        let common = Common {
+            id: &self.id,
+            label: &props.label,
+            help: props.help.as_ref(),
+            error: draft_error.as_ref().or(props.error.as_ref()),
+            disabled: props.disabled,
+            class: &props.class,
+        };
+
+        let value = match &self.draft {
+            Some(draft) => draft.input.clone(),
+            None => props.value.as_ref().map(N::to_string).unwrap_or_default(),
+        };
+        let step = props.step.as_ref().map(N::to_string);
+        let mut control = input()
+            .type_number()
+            .value(value)
+            .attr("step", step.unwrap_or_else(|| "any".to_string()))
+            .oninput(ctx.link().callback(input_msg));
+        if let Some(min) = &props.min {
+            control = control.attr("min", min.to_string());
+        }
+        if let Some(max) = &props.max {
+            control = control.attr("max", max.to_string());
+        }
+        common.view(Layout::Text, common.control(control).to_vnode())
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Input(input) => {
+                match parse_input(&input, props.min.as_ref(), props.max.as_ref()) {
+                    Ok(value) => {
+                        self.draft = None;
+                        props.on_change.emit(value);
+                    }
+                    Err(error) => self.draft = Some(Draft { input, error }),
+                }
+            }
+            Msg::BadInput => {
+                self.draft = Some(Draft {
+                    input: String::new(),
+                    error: "not valid".to_string(),
+                })
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().value != old_props.value {
+            self.draft = None;
+        }
+        true
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Date input with a typed [Date], see [NumberField].
+pub struct DateField {
+    id: String,
+    draft: Option<Draft>,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DateFieldProps {
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub value: Option<Date>,
+    /// Receives valid dates, or `None` if the input was cleared.
+    #[prop_or_default]
+    pub on_change: Callback<Option<Date>>,
+    #[prop_or_default]
+    pub min: Option<Date>,
+    #[prop_or_default]
+    pub max: Option<Date>,
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for DateFieldProps {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            value: None,
+            on_change: Callback::noop(),
+            min: None,
+            max: None,
+            help: None,
+            error: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl Component for DateField {
+    type Message = Msg;
+    type Properties = DateFieldProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            id: next_id(),
+            draft: None,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let draft_error = self.draft.as_ref().map(|draft| draft.error.clone().into());
+        let common = Common {
+            id: &self.id,
+            label: &props.label,
+            help: props.help.as_ref(),
+            error: draft_error.as_ref().or(props.error.as_ref()),
+            disabled: props.disabled,
+            class: &props.class,
+        };
+
+        let value = match &self.draft {
+            Some(draft) => draft.input.clone(),
+            None => props.value.map(|date| date.to_string()).unwrap_or_default(),
+        };
+        let mut control = input()
+            .type_date()
+            .value(value)
+            .oninput(ctx.link().callback(input_msg));
+        if let Some(min) = props.min {
+            control = control.attr("min", min.to_string());
+        }
+        if let Some(max) = props.max {
+            control = control.attr("max", max.to_string());
+        }
+        common.view(Layout::Text, common.control(control).to_vnode())
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Input(input) => {
+                match parse_input(&input, props.min.as_ref(), props.max.as_ref()) {
+                    Ok(value) => {
+                        self.draft = None;
+                        props.on_change.emit(value);
+                    }
+                    Err(error) => self.draft = Some(Draft { input, error }),
+                }
+            }
+            Msg::BadInput => {
+                self.draft = Some(Draft {
+                    input: String::new(),
+                    error: "not valid".to_string(),
+                })
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().value != old_props.value {
+            self.draft = None;
+        }
+        true
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Checkbox with its label, see [TextField].
+pub struct CheckboxField {
+    id: String,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct CheckboxFieldProps {
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub checked: bool,
+    #[prop_or_default]
+    pub on_change: Callback<bool>,
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for CheckboxFieldProps {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            checked: false,
+            on_change: Callback::noop(),
+            help: None,
+            error: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl CheckboxField {
+    fn view_checkbox(id: &str, props: &CheckboxFieldProps, switch: bool) -> Html {
+        let common = Common {
+            id,
+            label: &props.label,
+            help: props.help.as_ref(),
+            error: props.error.as_ref(),
+            disabled: props.disabled,
+            class: &props.class,
+        };
+
+        let on_change = props.on_change.clone();
+        let control = checkbox().checked(props.checked).onchange(move |e: Event| {
+            on_change.emit(e.target_unchecked_into::<HtmlInputElement>().checked())
+        });
+        let mut control = common.control(control);
+        if switch {
+            // merged with the classes set by `Common::control`
+            control = control
+                .classes(classes!("switch", control_classes(common.error.is_some())))
+                .attr("role", "switch");
+        }
+        common.view(Layout::Check, control.to_vnode())
+    }
+}
+
+impl Component for CheckboxField {
+    type Message = ();
+    type Properties = CheckboxFieldProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { id: next_id() }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        Self::view_checkbox(&self.id, ctx.props(), false)
+    }
+}
+
+/// On/off toggle, a checkbox with the `switch` role and class. It takes the
+/// same props as a [CheckboxField].
+pub struct Switch {
+    id: String,
+}
+
+impl Component for Switch {
+    type Message = ();
+    type Properties = CheckboxFieldProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { id: next_id() }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        CheckboxField::view_checkbox(&self.id, ctx.props(), true)
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Radio buttons for `options`, which are shown with their [Display]
+/// implementation.
+///
+/// ```no_run
+/// use yew_utils::components::fields::{RadioGroup, RadioGroupProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let size = use_state(|| Some("M"));
+/// comp_with::<RadioGroup<&'static str>>(RadioGroupProps {
+///     label: "Size".into(),
+///     options: vec!["S", "M", "L"],
+///     value: *size,
+///     on_change: Callback::from(move |value| size.set(Some(value))),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct RadioGroup<T> {
+    id: String,
+    _option: PhantomData<T>,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct RadioGroupProps<T: PartialEq> {
+    pub label: AttrValue,
+    pub options: Vec<T>,
+    #[prop_or_default]
+    pub value: Option<T>,
+    #[prop_or_default]
+    pub on_change: Callback<T>,
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl<T: PartialEq> Default for RadioGroupProps<T> {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            options: Vec::new(),
+            value: None,
+            on_change: Callback::noop(),
+            help: None,
+            error: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl<T> Component for RadioGroup<T>
+where
+    T: Display + Clone + PartialEq + 'static,
+{
+    type Message = ();
+    type Properties = RadioGroupProps<T>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            id: next_id(),
+            _option: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let common = Common {
+            id: &self.id,
+            label: &props.label,
+            help: props.help.as_ref(),
+            error: props.error.as_ref(),
+            disabled: props.disabled,
+            class: &props.class,
+        };
+
+        let options = props.options.iter().enumerate().map(|(i, option)| {
+            let on_change = props.on_change.clone();
+            let value = option.clone();
+            let mut radio = input()
+                .type_radio()
+                .id(format!("{}-{i}", self.id))
+                .attr("name", self.id.clone())
+                .checked(props.value.as_ref() == Some(option))
+                .onchange(move |_| on_change.emit(value.clone()));
+            if props.disabled {
+                radio = radio.attr("disabled", "disabled");
+            }
+            choice(radio.to_vnode(), &option.to_string().into())
+        });
+
+        // the ARIA attributes go on the group, each radio has its own id
+        let mut group = tag("div")
+            .attr("role", "radiogroup")
+            .attr("aria-invalid", props.error.is_some().to_string())
+            .append_all(options);
+        if let Some(ids) = common.described_by() {
+            group = group.attr("aria-describedby", ids);
+        }
+        common.view(Layout::Group, group.to_vnode())
+    }
+}
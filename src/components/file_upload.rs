@@ -0,0 +1,604 @@
+//! File upload with a drop zone, filters and a list of the picked files.
+//!
+//! Files are dropped on the zone or chosen with the file dialog that opens
+//! when the zone is clicked or activated with Enter or Space. Files that don't
+//! match `accept` or are larger than `max_size` are listed as rejected, the
+//! others are read into bytes or text with a progress bar while reading.
+
+use std::fmt;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{File, FileList, FileReader, HtmlInputElement, ProgressEvent, Url};
+use yew::prelude::*;
+
+use crate::vdom::{button, div, img, input, li, progress, span, ul};
+
+/// Whether `accept`, a comma separated list of file extensions and MIME types
+/// like the `accept` attribute of file inputs, allows a file. An empty list
+/// allows any file.
+///
+/// ```
+/// use yew_utils::components::file_upload::accepts;
+///
+/// assert!(accepts("image/*,.pdf", "cat.PNG", "image/png"));
+/// assert!(accepts("image/*,.pdf", "paper.pdf", ""));
+/// assert!(!accepts("image/*,.pdf", "notes.txt", "text/plain"));
+/// assert!(accepts("text/csv", "data.csv", "text/csv"));
+/// assert!(accepts("", "anything", ""));
+/// ```
+pub fn accepts(accept: &str, name: &str, mime_type: &str) -> bool {
+    let name = name.to_lowercase();
+    let mime_type = mime_type.to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+    patterns.any(|pattern| {
+        if pattern.starts_with('.') {
+            name.ends_with(&pattern)
+        } else if let Some(group) = pattern.strip_suffix("/*") {
+            mime_type.split('/').next() == Some(group)
+        } else {
+            mime_type == pattern
+        }
+    })
+}
+
+/// `bytes` as a human readable size.
+///
+/// ```
+/// use yew_utils::components::file_upload::format_size;
+///
+/// assert_eq!(format_size(512), "512 B");
+/// assert_eq!(format_size(1536), "1.5 KB");
+/// assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+/// ```
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// The files of `list`, which can't change afterwards.
+fn files(list: FileList) -> Vec<File> {
+    (0..list.length()).filter_map(|i| list.get(i)).collect()
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Name, size and type of a picked file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileInfo {
+    pub name: String,
+    /// In bytes.
+    pub size: u64,
+    /// Empty if the browser doesn't know the type.
+    pub mime_type: String,
+}
+
+impl From<&File> for FileInfo {
+    fn from(file: &File) -> Self {
+        Self {
+            name: file.name(),
+            size: file.size() as u64,
+            mime_type: file.type_(),
+        }
+    }
+}
+
+/// How picked files are read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadAs {
+    #[default]
+    Bytes,
+    /// UTF-8 text.
+    Text,
+    /// Don't read the files, e.g. if they are uploaded with `on_files`.
+    Nothing,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileContent {
+    Bytes(Vec<u8>),
+    Text(String),
+}
+
+/// Why a file was not accepted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    Type,
+    TooLarge { max_size: u64 },
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type => write!(f, "file type not allowed"),
+            Self::TooLarge { max_size } => write!(f, "larger than {}", format_size(*max_size)),
+        }
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Reads a file, aborts reading when dropped.
+struct Reader {
+    reader: FileReader,
+    _onprogress: Closure<dyn FnMut(ProgressEvent)>,
+    _onload: Closure<dyn FnMut()>,
+    _onerror: Closure<dyn FnMut()>,
+}
+
+impl Reader {
+    fn new(
+        file: &File,
+        read_as: ReadAs,
+        on_progress: Callback<(f64, f64)>,
+        on_done: Callback<Result<FileContent, String>>,
+    ) -> Result<Self, JsValue> {
+        let reader = FileReader::new()?;
+
+        let onprogress = Closure::<dyn FnMut(ProgressEvent)>::new(move |e: ProgressEvent| {
+            if e.length_computable() {
+                on_progress.emit((e.loaded(), e.total()));
+            }
+        });
+        let onload = {
+            let reader = reader.clone();
+            let on_done = on_done.clone();
+            Closure::<dyn FnMut()>::new(move || {
+                let content = reader.result().map_err(|_| "no result".to_string());
+                on_done.emit(content.map(|result| match read_as {
+                    ReadAs::Text => FileContent::Text(result.as_string().unwrap_or_default()),
+                    _ => FileContent::Bytes(js_sys::Uint8Array::new(&result).to_vec()),
+                }));
+            })
+        };
+        let onerror = {
+            let reader = reader.clone();
+            Closure::<dyn FnMut()>::new(move || {
+                let message = reader
+                    .error()
+                    .map(|error| error.message())
+                    .unwrap_or_else(|| "could not read file".to_string());
+                on_done.emit(Err(message));
+            })
+        };
+
+        reader.set_onprogress(Some(onprogress.as_ref().unchecked_ref()));
+        reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+        reader.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        match read_as {
+            ReadAs::Text => reader.read_as_text(file)?,
+            _ => reader.read_as_array_buffer(file)?,
+        }
+
+        Ok(Self {
+            reader,
+            _onprogress: onprogress,
+            _onload: onload,
+            _onerror: onerror,
+        })
+    }
+}
+
+impl Drop for Reader {
+    fn drop(&mut self) {
+        if self.reader.ready_state() == FileReader::LOADING {
+            self.reader.abort();
+        }
+        self.reader.set_onprogress(None);
+        self.reader.set_onload(None);
+        self.reader.set_onerror(None);
+    }
+}
+
+enum Status {
+    Reading { loaded: f64, total: f64 },
+    Done,
+    Rejected(Rejection),
+    Failed(String),
+}
+
+struct Entry {
+    id: usize,
+    info: FileInfo,
+    status: Status,
+    /// Object URL of images, revoked when the entry is removed.
+    preview: Option<String>,
+    /// Kept until the entry is removed, the reader calls back into the
+    /// component while it is read.
+    _reader: Option<Reader>,
+}
+
+impl Drop for Entry {
+    fn drop(&mut self) {
+        if let Some(url) = &self.preview {
+            let _ = Url::revoke_object_url(url);
+        }
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Drop zone for files with a list of the picked files. Each entry shows the
+/// name, size, a thumbnail for images, the reading progress or why the file
+/// was rejected, and a button to remove it.
+///
+/// ```no_run
+/// use yew_utils::components::file_upload::{FileContent, FileUpload, FileUploadProps, ReadAs};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let lines = use_state(|| 0);
+/// comp_with::<FileUpload>(FileUploadProps {
+///     accept: Some(".csv,text/csv".into()),
+///     max_size: Some(10 * 1024 * 1024),
+///     multiple: true,
+///     read_as: ReadAs::Text,
+///     on_read: Callback::from(move |(_file, content)| {
+///         if let FileContent::Text(text) = content {
+///             lines.set(*lines + text.lines().count());
+///         }
+///     }),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct FileUpload {
+    input: NodeRef,
+    entries: Vec<Entry>,
+    next_id: usize,
+    /// Nesting of `dragenter` and `dragleave` events while files are dragged
+    /// over the zone.
+    drag_depth: usize,
+}
+
+pub enum Msg {
+    Browse,
+    Picked(Vec<File>),
+    DragEntered,
+    DragLeft,
+    Progressed(usize, f64, f64),
+    Read(usize, Result<FileContent, String>),
+    Removed(usize),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FileUploadProps {
+    /// Allowed file extensions and MIME types, e.g. `image/*,.pdf`.
+    #[prop_or_default]
+    pub accept: Option<AttrValue>,
+    /// In bytes.
+    #[prop_or_default]
+    pub max_size: Option<u64>,
+    #[prop_or_default]
+    pub multiple: bool,
+    #[prop_or_default]
+    pub read_as: ReadAs,
+    /// Receives the accepted files, before they are read.
+    #[prop_or_default]
+    pub on_files: Callback<Vec<File>>,
+    #[prop_or_default]
+    pub on_read: Callback<(FileInfo, FileContent)>,
+    #[prop_or_default]
+    pub on_error: Callback<(FileInfo, String)>,
+    #[prop_or_default]
+    pub on_remove: Callback<FileInfo>,
+    /// Content of the drop zone, a short hint if not set.
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for FileUploadProps {
+    fn default() -> Self {
+        Self {
+            accept: None,
+            max_size: None,
+            multiple: false,
+            read_as: ReadAs::Bytes,
+            on_files: Callback::noop(),
+            on_read: Callback::noop(),
+            on_error: Callback::noop(),
+            on_remove: Callback::noop(),
+            children: Children::default(),
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl FileUpload {
+    fn check(props: &FileUploadProps, info: &FileInfo) -> Result<(), Rejection> {
+        let accept = props.accept.as_deref().unwrap_or_default();
+        if !accepts(accept, &info.name, &info.mime_type) {
+            return Err(Rejection::Type);
+        }
+        match props.max_size {
+            Some(max_size) if info.size > max_size => Err(Rejection::TooLarge { max_size }),
+            _ => Ok(()),
+        }
+    }
+
+    fn add(&mut self, ctx: &Context<Self>, files: Vec<File>) {
+        let props = ctx.props();
+        let files = files
+            .into_iter()
+            .take(if props.multiple { usize::MAX } else { 1 });
+        if !props.multiple {
+            self.entries.clear();
+        }
+
+        let mut accepted = Vec::new();
+        for file in files {
+            let id = self.next_id;
+            self.next_id += 1;
+            let info = FileInfo::from(&file);
+            let preview = info
+                .mime_type
+                .starts_with("image/")
+                .then(|| Url::create_object_url_with_blob(&file).ok())
+                .flatten();
+
+            let (status, reader) = match Self::check(props, &info) {
+                Err(rejection) => (Status::Rejected(rejection), None),
+                Ok(()) if props.read_as == ReadAs::Nothing => (Status::Done, None),
+                Ok(()) => {
+                    let link = ctx.link();
+                    let reader = Reader::new(
+                        &file,
+                        props.read_as,
+                        link.callback(move |(loaded, total)| Msg::Progressed(id, loaded, total)),
+                        link.callback(move |content| Msg::Read(id, content)),
+                    );
+                    match reader {
+                        Ok(reader) => (
+                            Status::Reading {
+                                loaded: 0.0,
+                                total: info.size as f64,
+                            },
+                            Some(reader),
+                        ),
+                        Err(_) => (Status::Failed("could not read file".to_string()), None),
+                    }
+                }
+            };
+            if !matches!(status, Status::Rejected(_)) {
+                accepted.push(file);
+            }
+            self.entries.push(Entry {
+                id,
+                info,
+                status,
+                preview,
+                _reader: reader,
+            });
+        }
+        if !accepted.is_empty() {
+            props.on_files.emit(accepted);
+        }
+    }
+
+    fn view_entry(&self, ctx: &Context<Self>, entry: &Entry) -> Html {
+        let (status_class, status) = match &entry.status {
+            Status::Reading { loaded, total } => (
+                "file-upload-item--reading",
+                progress()
+                    .attr("max", total.to_string())
+                    .attr("value", loaded.to_string())
+                    .attr("aria-label", format!("Reading {}", entry.info.name))
+                    .to_vnode(),
+            ),
+            Status::Done => ("file-upload-item--done", Html::default()),
+            Status::Rejected(rejection) => (
+                "file-upload-item--rejected",
+                span()
+                    .class("file-upload-error")
+                    .text(rejection.to_string())
+                    .to_vnode(),
+            ),
+            Status::Failed(message) => (
+                "file-upload-item--failed",
+                span()
+                    .class("file-upload-error")
+                    .text(message.clone())
+                    .to_vnode(),
+            ),
+        };
+
+        let id = entry.id;
+        li().key(id)
+            .classes(classes!("file-upload-item", status_class))
+            .append_all(entry.preview.as_ref().map(|url| {
+                img()
+                    .class("file-upload-preview")
+                    .attr("src", url.clone())
+                    .attr("alt", "")
+            }))
+            .append(
+                span()
+                    .class("file-upload-name")
+                    .text(entry.info.name.clone()),
+            )
+            .append(
+                span()
+                    .class("file-upload-size")
+                    .text(format_size(entry.info.size)),
+            )
+            .append(status)
+            .append(
+                button()
+                    .attr("type", "button")
+                    .class("file-upload-remove")
+                    .attr("aria-label", format!("Remove {}", entry.info.name))
+                    .text("×")
+                    .onclick(ctx.link().callback(move |_| Msg::Removed(id))),
+            )
+            .to_vnode()
+    }
+}
+
+impl Component for FileUpload {
+    type Message = Msg;
+    type Properties = FileUploadProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            input: NodeRef::default(),
+            entries: Vec::new(),
+            next_id: 0,
+            drag_depth: 0,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let mut file_input = input()
+            .type_file()
+            .node_ref(self.input.clone())
+            .attr("hidden", "hidden")
+            .attr("tabindex", "-1")
+            // `Msg::Browse` clicks the input, the click must not reach the
+            // zone and browse again
+            .onclick(|e: MouseEvent| e.stop_propagation())
+            .onchange(link.batch_callback(|e: Event| {
+                let input = e.target_unchecked_into::<HtmlInputElement>();
+                // copied first, the list is live and cleared with the value
+                let files = input.files().map(files);
+                // so that picking the same file again is a change
+                input.set_value("");
+                files.map(Msg::Picked)
+            }));
+        if let Some(accept) = &props.accept {
+            file_input = file_input.attr("accept", accept.clone());
+        }
+        if props.multiple {
+            file_input = file_input.attr("multiple", "multiple");
+        }
+
+        let hint = match props.multiple {
+            true => "Drop files here or click to choose",
+            false => "Drop a file here or click to choose",
+        };
+        let mut zone = div()
+            .classes(classes!(
+                "file-upload-zone",
+                (self.drag_depth > 0).then_some("file-upload-zone--dragging"),
+            ))
+            .attr("role", "button")
+            .append(file_input)
+            .append_all(match props.children.is_empty() {
+                true => vec![span().text(hint).to_vnode()],
+                false => props.children.iter().collect(),
+            });
+        // dropping is prevented even when disabled, or the browser would
+        // navigate to the dropped file
+        zone = zone.ondragover(|e: DragEvent| e.prevent_default());
+        zone = match props.disabled {
+            true => zone
+                .attr("aria-disabled", "true")
+                .ondrop(|e: DragEvent| e.prevent_default()),
+            false => zone
+                .attr("tabindex", "0")
+                .onclick(link.callback(|_| Msg::Browse))
+                .onkeydown(link.batch_callback(|e: KeyboardEvent| {
+                    matches!(e.key().as_str(), "Enter" | " ").then(|| {
+                        e.prevent_default();
+                        Msg::Browse
+                    })
+                }))
+                .ondragenter(link.callback(|e: DragEvent| {
+                    e.prevent_default();
+                    Msg::DragEntered
+                }))
+                .ondragleave(link.callback(|_| Msg::DragLeft))
+                .ondrop(link.batch_callback(|e: DragEvent| {
+                    e.prevent_default();
+                    e.data_transfer()
+                        .and_then(|data| data.files())
+                        .map(|list| Msg::Picked(files(list)))
+                })),
+        };
+
+        div()
+            .classes(classes!(
+                "file-upload",
+                props.disabled.then_some("file-upload--disabled"),
+                props.class.clone()
+            ))
+            .append(zone)
+            .append_all((!self.entries.is_empty()).then(|| {
+                ul().class("file-upload-list")
+                    .append_all(self.entries.iter().map(|entry| self.view_entry(ctx, entry)))
+            }))
+            .to_vnode()
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Browse => {
+                if let Some(input) = self.input.cast::<HtmlInputElement>() {
+                    input.click();
+                }
+                return false;
+            }
+            Msg::Picked(files) => {
+                self.drag_depth = 0;
+                self.add(ctx, files);
+            }
+            Msg::DragEntered => self.drag_depth += 1,
+            Msg::DragLeft => self.drag_depth = self.drag_depth.saturating_sub(1),
+            Msg::Progressed(id, loaded, total) => {
+                let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) else {
+                    return false;
+                };
+                entry.status = Status::Reading { loaded, total };
+            }
+            Msg::Read(id, content) => {
+                let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) else {
+                    return false;
+                };
+                match content {
+                    Ok(content) => {
+                        entry.status = Status::Done;
+                        props.on_read.emit((entry.info.clone(), content));
+                    }
+                    Err(message) => {
+                        entry.status = Status::Failed(message.clone());
+                        props.on_error.emit((entry.info.clone(), message));
+                    }
+                }
+            }
+            Msg::Removed(id) => {
+                let Some(i) = self.entries.iter().position(|entry| entry.id == id) else {
+                    return false;
+                };
+                let entry = self.entries.remove(i);
+                props.on_remove.emit(entry.info.clone());
+            }
+        }
+        true
+    }
+}
@@ -1,4 +1,16 @@
+pub mod accordion;
+pub mod date_picker;
+mod fields_shared;
+pub mod file_upload;
+pub mod modal;
+pub mod placement;
+pub mod popover;
+pub mod slider;
 pub mod table;
+mod tabs_shared;
+pub mod toast;
+pub mod tooltip;
+pub mod virtual_list;
 
 #[cfg(feature = "mui-css")]
 #[path = "drop_down_muicss.rs"]
@@ -7,3 +19,19 @@ pub mod drop_down;
 #[cfg(not(feature = "mui-css"))]
 #[path = "drop_down_plain.rs"]
 pub mod drop_down;
+
+#[cfg(feature = "mui-css")]
+#[path = "fields_muicss.rs"]
+pub mod fields;
+
+#[cfg(not(feature = "mui-css"))]
+#[path = "fields_plain.rs"]
+pub mod fields;
+
+#[cfg(feature = "mui-css")]
+#[path = "tabs_muicss.rs"]
+pub mod tabs;
+
+#[cfg(not(feature = "mui-css"))]
+#[path = "tabs_plain.rs"]
+pub mod tabs;
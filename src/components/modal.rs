@@ -0,0 +1,276 @@
+//! Modal dialog on top of the native `<dialog>` element.
+//!
+//! The dialog is controlled by `open`: Escape and clicks on the backdrop emit
+//! `on_close` (unless disabled) and the parent is expected to set `open` to
+//! false. While open, focus is kept inside of the dialog and the
+//! page doesn't scroll.
+//!
+//! ```no_run
+//! use yew_utils::components::modal::{Modal, ModalProps};
+//! use yew_utils::vdom::*;
+//! use yew::prelude::*;
+//!
+//! # #[function_component(Example)]
+//! # fn example() -> Html {
+//! let open = use_state(|| false);
+//! let close = {
+//!     let open = open.clone();
+//!     Callback::from(move |_| open.set(false))
+//! };
+//!
+//! comp_with::<Modal>(ModalProps {
+//!     open: *open,
+//!     on_close: close.clone(),
+//!     header: h2().text("Delete file?").into(),
+//!     children: Children::new(vec![p().text("This can't be undone.").into()]),
+//!     footer: button().text("Cancel").onclick(move |_| close.emit(())).into(),
+//!     ..Default::default()
+//! })
+//! # .into()
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlDialogElement, HtmlElement};
+use yew::prelude::*;
+
+use crate::vdom::{dialog, div, footer, header};
+
+/// Elements that can receive the focus when tabbing through the dialog.
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+pub struct Modal {
+    id: String,
+    dialog: NodeRef,
+    /// Element that had the focus before the dialog was opened.
+    previous_focus: Option<HtmlElement>,
+    /// `overflow` of the body before its scrolling was locked.
+    body_overflow: Option<String>,
+}
+
+pub enum Msg {
+    CloseRequested,
+    /// The dialog was closed by the browser, e.g. by a `<form method="dialog">`.
+    Closed,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct ModalProps {
+    pub open: bool,
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    #[prop_or_default]
+    pub header: Html,
+    /// The body of the dialog.
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub footer: Html,
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    #[prop_or(true)]
+    pub close_on_backdrop: bool,
+    /// Keeps the page from scrolling while the dialog is open.
+    #[prop_or(true)]
+    pub lock_scroll: bool,
+    /// Classes of the `dialog` element.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for ModalProps {
+    fn default() -> Self {
+        Self {
+            open: false,
+            on_close: Callback::noop(),
+            header: Html::default(),
+            children: Children::default(),
+            footer: Html::default(),
+            close_on_escape: true,
+            close_on_backdrop: true,
+            lock_scroll: true,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl Modal {
+    fn next_id() -> String {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        format!("yew-modal-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn show(&mut self, dialog: &HtmlDialogElement, lock_scroll: bool) {
+        let document = document();
+        self.previous_focus = document
+            .as_ref()
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+        if lock_scroll {
+            if let Some(body) = document.and_then(|document| document.body()) {
+                let style = body.style();
+                self.body_overflow = style.get_property_value("overflow").ok();
+                let _ = style.set_property("overflow", "hidden");
+            }
+        }
+        if let Err(err) = dialog.show_modal() {
+            web_sys::console::error_2(&"could not open modal".into(), &err);
+        }
+    }
+
+    /// Restores what [show](Self::show) changed outside of the dialog.
+    fn restore(&mut self) {
+        if let Some(overflow) = self.body_overflow.take() {
+            if let Some(body) = document().and_then(|document| document.body()) {
+                let _ = body.style().set_property("overflow", &overflow);
+            }
+        }
+        if let Some(element) = self.previous_focus.take() {
+            let _ = element.focus();
+        }
+    }
+
+    /// Keeps Tab and Shift+Tab cycling through the focusable elements of the
+    /// dialog.
+    fn trap_focus(e: KeyboardEvent) {
+        if e.key() != "Tab" {
+            return;
+        }
+        let dialog = e
+            .current_target()
+            .and_then(|t| t.dyn_into::<Element>().ok());
+        let Some(Ok(focusable)) = dialog.map(|dialog| dialog.query_selector_all(FOCUSABLE)) else {
+            return;
+        };
+        let element = |i: u32| {
+            focusable
+                .item(i)
+                .and_then(|node| node.dyn_into::<HtmlElement>().ok())
+        };
+        let (Some(first), Some(last)) = (element(0), element(focusable.length().wrapping_sub(1)))
+        else {
+            e.prevent_default();
+            return;
+        };
+        let active = document().and_then(|document| document.active_element());
+        let active = active
+            .as_ref()
+            .map(|active| active.unchecked_ref::<HtmlElement>());
+        let target = match (e.shift_key(), active) {
+            (true, Some(active)) if *active == first => last,
+            (false, Some(active)) if *active == last => first,
+            _ => return,
+        };
+        e.prevent_default();
+        let _ = target.focus();
+    }
+
+    fn content_classes() -> Classes {
+        let mut classes = classes!("modal-content");
+        if cfg!(feature = "mui-css") {
+            classes.push("mui-panel");
+        }
+        classes
+    }
+}
+
+fn document() -> Option<web_sys::Document> {
+    web_sys::window().and_then(|window| window.document())
+}
+
+impl Component for Modal {
+    type Message = Msg;
+    type Properties = ModalProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            id: Self::next_id(),
+            dialog: NodeRef::default(),
+            previous_focus: None,
+            body_overflow: None,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let header_id = format!("{}-header", self.id);
+        let has_header = props.header != Html::default();
+
+        let close_on_escape = props.close_on_escape;
+        let close_on_backdrop = props.close_on_backdrop;
+        let mut dialog = dialog()
+            .node_ref(self.dialog.clone())
+            .classes(classes!("modal", props.class.clone()))
+            .onkeydown(Self::trap_focus)
+            // the dialog only closes when `open` changes
+            .oncancel(link.batch_callback(move |e: Event| {
+                e.prevent_default();
+                close_on_escape.then_some(Msg::CloseRequested)
+            }))
+            // the dialog has no padding, so only clicks on the backdrop have
+            // the dialog itself as target
+            .onclick(link.batch_callback(move |e: MouseEvent| {
+                (close_on_backdrop && e.target() == e.current_target())
+                    .then_some(Msg::CloseRequested)
+            }))
+            .onclose(link.callback(|_| Msg::Closed))
+            .style("padding: 0;");
+        if has_header {
+            dialog = dialog.attr("aria-labelledby", header_id.clone());
+        }
+
+        let content = div()
+            .classes(Self::content_classes())
+            .style(match cfg!(feature = "mui-css") {
+                true => "margin: 0;",
+                false => "padding: 1em;",
+            })
+            .append_all(has_header.then(|| {
+                header()
+                    .id(header_id)
+                    .class("modal-header")
+                    .append(props.header.clone())
+            }))
+            .append(div().class("modal-body").append_all(props.children.iter()))
+            .append_all(
+                (props.footer != Html::default())
+                    .then(|| footer().class("modal-footer").append(props.footer.clone())),
+            );
+
+        dialog.append(content).to_vnode()
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::CloseRequested => ctx.props().on_close.emit(()),
+            Msg::Closed => {
+                self.restore();
+                if ctx.props().open {
+                    ctx.props().on_close.emit(());
+                }
+            }
+        }
+        false
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let Some(dialog) = self.dialog.cast::<HtmlDialogElement>() else {
+            return;
+        };
+        match (ctx.props().open, dialog.open()) {
+            (true, false) => self.show(&dialog, ctx.props().lock_scroll),
+            (false, true) => {
+                dialog.close();
+                self.restore();
+            }
+            _ => {}
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        self.restore();
+    }
+}
@@ -0,0 +1,214 @@
+//! Placement of floating elements like popovers and tooltips next to an
+//! anchor, without any DOM access.
+//!
+//! ```
+//! use yew_utils::components::placement::{place, Align, Placement, Rect, Side};
+//!
+//! let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+//! let anchor = Rect::new(100.0, 100.0, 80.0, 20.0);
+//! let below = Placement::new(Side::Bottom, Align::Start);
+//!
+//! let position = place(&anchor, (120.0, 40.0), &viewport, below, 4.0);
+//! assert_eq!((position.x, position.y), (100.0, 124.0));
+//! assert_eq!(position.placement, below);
+//! ```
+
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Side {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// Alignment along the side of the anchor, e.g. `Start` is the left edge for
+/// `Side::Bottom` and the top edge for `Side::Right`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Placement {
+    pub side: Side,
+    pub align: Align,
+}
+
+impl Placement {
+    pub fn new(side: Side, align: Align) -> Self {
+        Self { side, align }
+    }
+}
+
+/// The name used in `data-placement` attributes, e.g. `top` or `bottom-end`.
+impl fmt::Display for Placement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            Side::Top => "top",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+            Side::Right => "right",
+        };
+        match self.align {
+            Align::Start => write!(f, "{side}-start"),
+            Align::Center => write!(f, "{side}"),
+            Align::End => write!(f, "{side}-end"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// Where to put the floating element, and the placement that was used after
+/// flipping.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+    pub placement: Placement,
+}
+
+/// Places a floating element of `size` (width, height) next to `anchor`,
+/// `offset` away from it.
+///
+/// If the element overflows the `viewport` on the preferred side, it flips to
+/// the opposite side when that overflows less. It is then shifted along the
+/// side of the anchor to stay inside of the viewport.
+///
+/// ```
+/// use yew_utils::components::placement::{place, Align, Placement, Rect, Side};
+///
+/// let viewport = Rect::new(0.0, 0.0, 800.0, 600.0);
+/// let below = Placement::new(Side::Bottom, Align::Center);
+///
+/// // no room below, so it flips above the anchor
+/// let anchor = Rect::new(300.0, 570.0, 100.0, 20.0);
+/// let position = place(&anchor, (100.0, 50.0), &viewport, below, 0.0);
+/// assert_eq!(position.placement, Placement::new(Side::Top, Align::Center));
+/// assert_eq!((position.x, position.y), (300.0, 520.0));
+///
+/// // centered below an anchor at the right edge it is shifted left
+/// let anchor = Rect::new(760.0, 100.0, 40.0, 20.0);
+/// let position = place(&anchor, (100.0, 50.0), &viewport, below, 0.0);
+/// assert_eq!(position.placement, below);
+/// assert_eq!((position.x, position.y), (700.0, 120.0));
+/// ```
+pub fn place(
+    anchor: &Rect,
+    size: (f64, f64),
+    viewport: &Rect,
+    placement: Placement,
+    offset: f64,
+) -> Position {
+    let preferred = position(anchor, size, placement, offset);
+    let flipped = Placement::new(placement.side.opposite(), placement.align);
+    let flipped = position(anchor, size, flipped, offset);
+    let overflowing = overflow(&preferred, size, viewport);
+    let mut position = match overflowing > 0.0 && overflow(&flipped, size, viewport) < overflowing {
+        true => flipped,
+        false => preferred,
+    };
+
+    let (width, height) = size;
+    if position.placement.side.is_vertical() {
+        position.x = shift(position.x, width, viewport.x, viewport.right());
+    } else {
+        position.y = shift(position.y, height, viewport.y, viewport.bottom());
+    }
+    position
+}
+
+fn position(
+    anchor: &Rect,
+    (width, height): (f64, f64),
+    placement: Placement,
+    offset: f64,
+) -> Position {
+    let align = |start: f64, length: f64, size: f64| match placement.align {
+        Align::Start => start,
+        Align::Center => start + (length - size) / 2.0,
+        Align::End => start + length - size,
+    };
+    let (x, y) = match placement.side {
+        Side::Top => (
+            align(anchor.x, anchor.width, width),
+            anchor.y - offset - height,
+        ),
+        Side::Bottom => (
+            align(anchor.x, anchor.width, width),
+            anchor.bottom() + offset,
+        ),
+        Side::Left => (
+            anchor.x - offset - width,
+            align(anchor.y, anchor.height, height),
+        ),
+        Side::Right => (
+            anchor.right() + offset,
+            align(anchor.y, anchor.height, height),
+        ),
+    };
+    Position { x, y, placement }
+}
+
+/// How far `position` sticks out of the viewport on its side.
+fn overflow(position: &Position, (width, height): (f64, f64), viewport: &Rect) -> f64 {
+    let overflow = match position.placement.side {
+        Side::Top => viewport.y - position.y,
+        Side::Bottom => position.y + height - viewport.bottom(),
+        Side::Left => viewport.x - position.x,
+        Side::Right => position.x + width - viewport.right(),
+    };
+    overflow.max(0.0)
+}
+
+/// Moves `start` so that `start..start + size` lies within `min..max`, or
+/// starts at `min` if it doesn't fit.
+fn shift(start: f64, size: f64, min: f64, max: f64) -> f64 {
+    start.min(max - size).max(min)
+}
@@ -0,0 +1,220 @@
+//! Floating content anchored to an element.
+//!
+//! The popover is rendered into a portal on the document body with fixed
+//! positioning, so it isn't clipped by overflow containers of the anchor. It
+//! is placed with [place](super::placement::place) and repositioned when the
+//! page scrolls or the window is resized.
+//!
+//! ```no_run
+//! use yew_utils::components::placement::{Align, Placement, Side};
+//! use yew_utils::components::popover::{Popover, PopoverProps};
+//! use yew_utils::vdom::*;
+//! use yew::prelude::*;
+//!
+//! # #[function_component(Example)]
+//! # fn example() -> Html {
+//! let anchor = use_node_ref();
+//! let open = use_state(|| false);
+//! let toggle = {
+//!     let open = open.clone();
+//!     move |_| open.set(!*open)
+//! };
+//!
+//! div()
+//!     .append(button().node_ref(anchor.clone()).text("Options").onclick(toggle))
+//!     .append(comp_with::<Popover>(PopoverProps {
+//!         anchor,
+//!         open: *open,
+//!         placement: Placement::new(Side::Bottom, Align::Start),
+//!         children: Children::new(vec![p().text("popover content").into()]),
+//!         ..Default::default()
+//!     }))
+//!     .into()
+//! # }
+//! ```
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+use super::placement::{place, Placement, Position, Rect};
+use crate::vdom::{div, portal_to_body};
+
+/// Scroll (of any element) and resize listeners on the window, removed when
+/// dropped.
+struct WindowListeners {
+    callback: Closure<dyn FnMut()>,
+}
+
+impl WindowListeners {
+    fn new(callback: Callback<()>) -> Option<Self> {
+        let window = web_sys::window()?;
+        let callback = Closure::<dyn FnMut()>::new(move || callback.emit(()));
+        let function = callback.as_ref().unchecked_ref();
+        // scroll events don't bubble, but they can be captured
+        window
+            .add_event_listener_with_callback_and_bool("scroll", function, true)
+            .ok()?;
+        window
+            .add_event_listener_with_callback("resize", function)
+            .ok()?;
+        Some(Self { callback })
+    }
+}
+
+impl Drop for WindowListeners {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let function = self.callback.as_ref().unchecked_ref();
+            let _ = window.remove_event_listener_with_callback_and_bool("scroll", function, true);
+            let _ = window.remove_event_listener_with_callback("resize", function);
+        }
+    }
+}
+
+fn bounds(element: &Element) -> Rect {
+    let rect = element.get_bounding_client_rect();
+    Rect::new(rect.x(), rect.y(), rect.width(), rect.height())
+}
+
+fn viewport() -> Option<Rect> {
+    let window = web_sys::window()?;
+    let width = window.inner_width().ok()?.as_f64()?;
+    let height = window.inner_height().ok()?.as_f64()?;
+    Some(Rect::new(0.0, 0.0, width, height))
+}
+
+pub struct Popover {
+    floating: NodeRef,
+    /// Unknown until the popover was rendered and measured.
+    position: Option<Position>,
+    listeners: Option<WindowListeners>,
+}
+
+pub enum Msg {
+    Repositioned,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct PopoverProps {
+    pub anchor: NodeRef,
+    #[prop_or_default]
+    pub open: bool,
+    /// The preferred placement, the side is flipped if there is not enough
+    /// room.
+    #[prop_or_default]
+    pub placement: Placement,
+    /// Distance to the anchor in pixels.
+    #[prop_or(8.0)]
+    pub offset: f64,
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for PopoverProps {
+    fn default() -> Self {
+        Self {
+            anchor: NodeRef::default(),
+            open: false,
+            placement: Placement::default(),
+            offset: 8.0,
+            children: Children::default(),
+            class: Classes::new(),
+        }
+    }
+}
+
+impl Popover {
+    fn measure(&self, props: &PopoverProps) -> Option<Position> {
+        let anchor = props.anchor.cast::<Element>()?;
+        let floating = bounds(&self.floating.cast::<Element>()?);
+        Some(place(
+            &bounds(&anchor),
+            (floating.width, floating.height),
+            &viewport()?,
+            props.placement,
+            props.offset,
+        ))
+    }
+
+    /// Updates the position, returns if it changed.
+    fn reposition(&mut self, props: &PopoverProps) -> bool {
+        let position = self.measure(props);
+        let changed = position.is_some() && position != self.position;
+        if changed {
+            self.position = position;
+        }
+        changed
+    }
+}
+
+impl Component for Popover {
+    type Message = Msg;
+    type Properties = PopoverProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            floating: NodeRef::default(),
+            position: None,
+            listeners: None,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        if !props.open {
+            return Html::default();
+        }
+
+        let (style, placement) = match self.position {
+            Some(Position { x, y, placement }) => (format!("left: {x}px; top: {y}px;"), placement),
+            None => (
+                "left: 0; top: 0; visibility: hidden;".to_string(),
+                props.placement,
+            ),
+        };
+        let mut classes = classes!("popover", props.class.clone());
+        if cfg!(feature = "mui-css") {
+            classes.push("mui-panel");
+        }
+        let popover = div()
+            .node_ref(self.floating.clone())
+            .classes(classes)
+            .attr("data-placement", placement.to_string())
+            .style(format!(
+                "position: fixed; z-index: 1000; margin: 0; {style}"
+            ))
+            .append_all(ctx.props().children.iter());
+        portal_to_body().append(popover).to_vnode()
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Repositioned => self.reposition(ctx.props()),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().open && !old_props.open {
+            self.position = None;
+        }
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if !ctx.props().open {
+            self.listeners = None;
+            return;
+        }
+        if self.listeners.is_none() {
+            self.listeners = WindowListeners::new(ctx.link().callback(|_| Msg::Repositioned));
+        }
+        // the size of the content may have changed
+        if self.measure(ctx.props()) != self.position {
+            ctx.link().send_message(Msg::Repositioned);
+        }
+    }
+}
@@ -0,0 +1,513 @@
+//! Sliders for a value or a range of values, with steps, marks and value labels.
+//!
+//! Each thumb is a native `input type=range`, so sliders can be used with the
+//! arrow, Page Up/Down, Home and End keys. The markup is a `div.slider` with a
+//! `.slider-track` and a `.slider-fill` between the thumbs, the thumbs of a
+//! [RangeSlider] are stacked on the same track. The fill and the value labels
+//! are positioned with `left` and `width` percentages.
+//!
+//! A [RangeSlider] renders a `style` element, scoped to its `div.slider`, that
+//! does the stacking: both inputs are `position: absolute` on top of each
+//! other and ignore the pointer (`pointer-events: none`) except on their
+//! thumbs (`::-webkit-slider-thumb` and `::-moz-range-thumb`), so each thumb
+//! can be dragged although the other input covers it.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::vdom::{datalist, div, input, option, output, span, tag, Tag, TagTypeInput};
+
+/// A labelled position on the track of a slider.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mark {
+    pub value: f64,
+    pub label: Option<AttrValue>,
+}
+
+impl Mark {
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    #[must_use]
+    pub fn label(mut self, label: impl Into<AttrValue>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// `value` clamped to `min..=max` and rounded to the closest step from `min`.
+///
+/// ```
+/// use yew_utils::components::slider::snap;
+///
+/// assert_eq!(snap(7.0, 0.0, 10.0, Some(5.0)), 5.0);
+/// assert_eq!(snap(8.0, 0.0, 10.0, Some(5.0)), 10.0);
+/// assert_eq!(snap(12.0, 0.0, 10.0, None), 10.0);
+/// assert_eq!(snap(0.34, 0.0, 1.0, Some(0.1)), 0.3);
+/// ```
+pub fn snap(value: f64, min: f64, max: f64, step: Option<f64>) -> f64 {
+    let value = value.clamp(min, max);
+    match step {
+        Some(step) if step > 0.0 => {
+            let snapped = min + ((value - min) / step).round() * step;
+            // avoid float noise like 0.30000000000000004
+            let snapped = (snapped * 1e9).round() / 1e9;
+            if snapped > max {
+                snapped - step
+            } else {
+                snapped
+            }
+        }
+        _ => value,
+    }
+}
+
+/// The position of `value` on the track from `min` to `max` in percent.
+///
+/// ```
+/// use yew_utils::components::slider::percent;
+///
+/// assert_eq!(percent(25.0, 0.0, 100.0), 25.0);
+/// assert_eq!(percent(0.5, -1.0, 1.0), 75.0);
+/// assert_eq!(percent(3.0, 3.0, 3.0), 0.0);
+/// ```
+pub fn percent(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+    (value.clamp(min, max) - min) / (max - min) * 100.0
+}
+
+fn next_id() -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    format!("yew-slider-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// What sliders and range sliders have in common.
+struct Track<'a> {
+    id: &'a str,
+    min: f64,
+    max: f64,
+    step: Option<f64>,
+    marks: &'a [Mark],
+    format: &'a Option<Callback<f64, String>>,
+    disabled: bool,
+}
+
+impl Track<'_> {
+    fn format(&self, value: f64) -> String {
+        match self.format {
+            Some(format) => format.emit(value),
+            None => value.to_string(),
+        }
+    }
+
+    fn thumb(&self, value: f64, label: String, on_input: Callback<f64>) -> Tag<TagTypeInput> {
+        let mut thumb = input()
+            .attr("type", "range")
+            .class("slider-thumb")
+            .attr("min", self.min.to_string())
+            .attr("max", self.max.to_string())
+            .attr(
+                "step",
+                self.step
+                    .map(|step| step.to_string())
+                    .unwrap_or_else(|| "any".to_string()),
+            )
+            .value(value.to_string())
+            .attr("aria-label", label)
+            .attr("aria-valuetext", self.format(value))
+            .oninput(move |e: InputEvent| {
+                let input = e.target_unchecked_into::<HtmlInputElement>();
+                if let Ok(value) = input.value().parse() {
+                    on_input.emit(value);
+                }
+            });
+        if !self.marks.is_empty() {
+            thumb = thumb.attr("list", format!("{}-marks", self.id));
+        }
+        if self.disabled {
+            thumb = thumb.attr("disabled", "disabled");
+        }
+        thumb
+    }
+
+    fn value_label(&self, value: f64) -> Html {
+        output()
+            .class("slider-value")
+            .attr(
+                "style",
+                format!("left: {}%", percent(value, self.min, self.max)),
+            )
+            .text(self.format(value))
+            .to_vnode()
+    }
+
+    fn view(
+        &self,
+        (lo, hi): (f64, f64),
+        thumbs: Vec<Html>,
+        labels: Option<Vec<Html>>,
+        class: Classes,
+    ) -> Html {
+        let left = percent(lo, self.min, self.max);
+        let right = percent(hi, self.min, self.max);
+        let track = div().class("slider-track").append(
+            div()
+                .class("slider-fill")
+                .attr("style", format!("left: {left}%; width: {}%", right - left)),
+        );
+
+        let mut slider = div()
+            .id(self.id.to_string())
+            .classes(classes!(
+                "slider",
+                self.disabled.then_some("slider--disabled"),
+                class
+            ))
+            .append(track)
+            .append_all(thumbs);
+        if let Some(labels) = labels {
+            slider = slider.append_all(labels);
+        }
+        if !self.marks.is_empty() {
+            let options = self.marks.iter().map(|mark| {
+                let mut option = option().attr("value", mark.value.to_string());
+                if let Some(label) = &mark.label {
+                    option = option.attr("label", label.clone());
+                }
+                option
+            });
+            let marks = self.marks.iter().map(|mark| {
+                span()
+                    .class("slider-mark")
+                    .attr(
+                        "style",
+                        format!("left: {}%", percent(mark.value, self.min, self.max)),
+                    )
+                    .text(mark.label.clone().unwrap_or_default())
+            });
+            slider = slider
+                .append(
+                    datalist()
+                        .id(format!("{}-marks", self.id))
+                        .append_all(options),
+                )
+                .append(
+                    div()
+                        .class("slider-marks")
+                        .attr("aria-hidden", "true")
+                        .append_all(marks),
+                );
+        }
+        slider.to_vnode()
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Slider for a single value. The value is controlled by the parent, which
+/// receives the new value with `on_change`.
+///
+/// ```no_run
+/// use yew_utils::components::slider::{Mark, Slider, SliderProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let opacity = use_state(|| 0.5);
+/// comp_with::<Slider>(SliderProps {
+///     label: "Opacity".into(),
+///     value: *opacity,
+///     max: 1.0,
+///     step: Some(0.05),
+///     marks: vec![Mark::new(0.0).label("0%"), Mark::new(1.0).label("100%")],
+///     show_value: true,
+///     on_change: Callback::from(move |value| opacity.set(value)),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct Slider {
+    id: String,
+}
+
+pub enum Msg {
+    Input(f64),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct SliderProps {
+    /// Accessible name of the thumb.
+    pub label: AttrValue,
+    pub value: f64,
+    #[prop_or_default]
+    pub on_change: Callback<f64>,
+    #[prop_or(0.0)]
+    pub min: f64,
+    #[prop_or(100.0)]
+    pub max: f64,
+    /// Any value between `min` and `max` if not set.
+    #[prop_or_default]
+    pub step: Option<f64>,
+    #[prop_or_default]
+    pub marks: Vec<Mark>,
+    /// Show the value above the thumb.
+    #[prop_or_default]
+    pub show_value: bool,
+    /// Formats the value labels and the `aria-valuetext`.
+    #[prop_or_default]
+    pub format: Option<Callback<f64, String>>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for SliderProps {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            value: 0.0,
+            on_change: Callback::noop(),
+            min: 0.0,
+            max: 100.0,
+            step: None,
+            marks: Vec::new(),
+            show_value: false,
+            format: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl Component for Slider {
+    type Message = Msg;
+    type Properties = SliderProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { id: next_id() }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let track = Track {
+            id: &self.id,
+            min: props.min,
+            max: props.max,
+            step: props.step,
+            marks: &props.marks,
+            format: &props.format,
+            disabled: props.disabled,
+        };
+        let value = snap(props.value, props.min, props.max, props.step);
+        let thumb = track.thumb(
+            value,
+            props.label.to_string(),
+            ctx.link().callback(Msg::Input),
+        );
+        let labels = props.show_value.then(|| vec![track.value_label(value)]);
+        track.view(
+            (props.min, value),
+            vec![thumb.to_vnode()],
+            labels,
+            props.class.clone(),
+        )
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Input(value) => {
+                let value = snap(value, props.min, props.max, props.step);
+                if value != props.value {
+                    props.on_change.emit(value);
+                }
+            }
+        }
+        // resets the thumb if the parent doesn't take the value
+        true
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+/// Slider for a range of values with two thumbs, emitting `(lo, hi)`. A thumb
+/// can't be moved past the other one.
+///
+/// ```no_run
+/// use yew_utils::components::slider::{RangeSlider, RangeSliderProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let price = use_state(|| (20.0, 80.0));
+/// comp_with::<RangeSlider>(RangeSliderProps {
+///     label: "Price".into(),
+///     value: *price,
+///     step: Some(5.0),
+///     show_values: true,
+///     format: Some(Callback::from(|value| format!("${value}"))),
+///     on_change: Callback::from(move |range| price.set(range)),
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct RangeSlider {
+    id: String,
+}
+
+pub enum RangeMsg {
+    LoInput(f64),
+    HiInput(f64),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct RangeSliderProps {
+    /// Accessible name of the range, the thumbs are named "`label` minimum"
+    /// and "`label` maximum".
+    pub label: AttrValue,
+    pub value: (f64, f64),
+    #[prop_or_default]
+    pub on_change: Callback<(f64, f64)>,
+    #[prop_or(0.0)]
+    pub min: f64,
+    #[prop_or(100.0)]
+    pub max: f64,
+    /// Any value between `min` and `max` if not set.
+    #[prop_or_default]
+    pub step: Option<f64>,
+    #[prop_or_default]
+    pub marks: Vec<Mark>,
+    /// Show the values above the thumbs.
+    #[prop_or_default]
+    pub show_values: bool,
+    /// Formats the value labels and the `aria-valuetext`.
+    #[prop_or_default]
+    pub format: Option<Callback<f64, String>>,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for RangeSliderProps {
+    fn default() -> Self {
+        Self {
+            label: AttrValue::default(),
+            value: (0.0, 100.0),
+            on_change: Callback::noop(),
+            min: 0.0,
+            max: 100.0,
+            step: None,
+            marks: Vec::new(),
+            show_values: false,
+            format: None,
+            disabled: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl RangeSlider {
+    /// Stacks the thumbs of the slider with `id`, see the [module](self) docs.
+    fn stylesheet(id: &str) -> String {
+        [
+            format!("#{id} {{ position: relative; }}"),
+            format!(
+                "#{id} > .slider-thumb {{ position: absolute; left: 0; width: 100%; margin: 0; pointer-events: none; }}"
+            ),
+            format!("#{id} > .slider-thumb::-webkit-slider-thumb {{ pointer-events: auto; }}"),
+            format!("#{id} > .slider-thumb::-moz-range-thumb {{ pointer-events: auto; }}"),
+        ]
+        .join("\n")
+    }
+
+    fn range(props: &RangeSliderProps) -> (f64, f64) {
+        let lo = snap(props.value.0, props.min, props.max, props.step);
+        let hi = snap(props.value.1, props.min, props.max, props.step);
+        (lo.min(hi), hi.max(lo))
+    }
+}
+
+impl Component for RangeSlider {
+    type Message = RangeMsg;
+    type Properties = RangeSliderProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self { id: next_id() }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let track = Track {
+            id: &self.id,
+            min: props.min,
+            max: props.max,
+            step: props.step,
+            marks: &props.marks,
+            format: &props.format,
+            disabled: props.disabled,
+        };
+        let (lo, hi) = Self::range(props);
+        let mut lo_thumb = track
+            .thumb(
+                lo,
+                format!("{} minimum", props.label),
+                ctx.link().callback(RangeMsg::LoInput),
+            )
+            .class("slider-thumb slider-thumb--lo");
+        // the hi thumb is on top and can't move further right at the maximum,
+        // the lo thumb below it has to be reachable then
+        if lo == hi && hi >= props.max {
+            lo_thumb = lo_thumb.attr("style", "z-index: 1");
+        }
+        let thumbs = vec![
+            lo_thumb.to_vnode(),
+            track
+                .thumb(
+                    hi,
+                    format!("{} maximum", props.label),
+                    ctx.link().callback(RangeMsg::HiInput),
+                )
+                .class("slider-thumb slider-thumb--hi")
+                .to_vnode(),
+        ];
+        let labels = props
+            .show_values
+            .then(|| vec![track.value_label(lo), track.value_label(hi)]);
+        let slider = track.view(
+            (lo, hi),
+            thumbs,
+            labels,
+            classes!("slider--range", props.class.clone()),
+        );
+        [
+            tag("style").text(Self::stylesheet(&self.id)).to_vnode(),
+            slider,
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        let (lo, hi) = Self::range(props);
+        let range = match msg {
+            RangeMsg::LoInput(value) => (snap(value, props.min, props.max, props.step).min(hi), hi),
+            RangeMsg::HiInput(value) => (lo, snap(value, props.min, props.max, props.step).max(lo)),
+        };
+        if range != (lo, hi) {
+            props.on_change.emit(range);
+        }
+        // resets the thumbs if the parent doesn't take the range
+        true
+    }
+}
@@ -1,8 +1,60 @@
+use web_sys::{Element, HtmlInputElement};
 use yew::prelude::*;
 
-use crate::vdom::{comp_with, tag};
+use crate::components::virtual_list::{Virtualizer, Window};
+use crate::vdom::{checkbox, comp_with, div, tag, td, th, tr};
 
-/// Table component around html tables.
+mod aggregate;
+mod column;
+mod data_table;
+mod edit;
+mod export;
+mod filter;
+mod layout;
+mod pagination;
+mod selection;
+mod sort;
+mod style;
+mod tree;
+
+use selection::Selection;
+
+pub use aggregate::{group_indices, Aggregate};
+pub use column::{Column, RowFn};
+pub use data_table::{DataTable, DataTableProps};
+pub use edit::{CellEdit, Editor};
+pub use export::{download, export, to_csv, to_json, ExportFormat};
+pub use filter::{filtered_indices, Filter, FilterState, FilterValue};
+pub use layout::ColumnLayout;
+pub use pagination::{page_count, page_range, PageRequest, Pagination, ServerPaging};
+pub use selection::{RowKey, SelectionMode};
+pub use sort::{sort_indices, sorted_indices, toggle_sort, SortDirection, SortKey};
+pub use tree::RowChildren;
+
+pub use crate::components::virtual_list::RowHeight;
+
+/// Table component around html tables. Columns that are already `th`
+/// elements are used as is, other nodes are wrapped in a `th`.
+///
+/// For a typed table with sortable columns see [DataTable]. Long tables can be
+/// rendered with [VirtualScroll].
+///
+/// The `table` element only gets the `mui-table` class when the `mui-css`
+/// feature is enabled. Other classes and the striped, bordered, compact and
+/// hoverable variants can be set in [TableProps].
+///
+/// The head and the foot (`footer` rows) of the table can be kept in view
+/// while scrolling with `sticky_header` and `sticky_footer`. They stick to
+/// the nearest scroll container: the one of [VirtualScroll], the one added by
+/// `max_height`, or else the page. `frozen_start` and `frozen_end` leading and
+/// trailing columns stay in view while scrolling horizontally, for this the
+/// table is put into a scroll container. Cells of auxiliary rows (rows with a
+/// `data-auxiliary` attribute) are not frozen.
+///
+/// Rows can be selected with checkboxes when a [SelectionMode] is set. Rows are
+/// identified by their key (`tr().key(..)`), rows without a key or with a
+/// `data-selectable="false"` attribute cannot be selected. In multi mode a header checkbox (de)selects all rows and
+/// shift-click selects a range of rows.
 ///
 /// Example:
 /// ```no_run
@@ -33,49 +85,359 @@ use crate::vdom::{comp_with, tag};
 /// # todo!();
 /// # }
 /// ```
-
-pub struct Table;
+pub struct Table {
+    id: String,
+    virtualizer: Virtualizer,
+    container: NodeRef,
+    body: NodeRef,
+    selection: Selection,
+    select_all: NodeRef,
+    head: NodeRef,
+    frozen: style::FrozenOffsets,
+}
 
 impl Table {
     pub fn render(columns: Children, rows: Children) -> Html {
         comp_with::<Table>(TableProps {
             columns,
             children: rows,
+            ..Default::default()
         })
         .to_vnode()
     }
 }
 
-#[derive(PartialEq, Properties)]
+/// Virtual scrolling: only the rows in view (plus `overscan`) are rendered
+/// inside of a scroll container of `height` pixels. Rows should be keyed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VirtualScroll {
+    pub height: f64,
+    pub row_height: RowHeight,
+    pub overscan: usize,
+}
+
+pub enum Msg {
+    Scrolled,
+    RowToggled { key: RowKey, range: bool },
+    AllToggled,
+    FrozenMeasured(style::FrozenOffsets),
+}
+
+#[derive(Default, PartialEq, Properties)]
 pub struct TableProps {
     pub columns: Children,
     pub children: Children,
+    /// Additional rows in the table head, below the column headers.
+    #[prop_or_default]
+    pub header_rows: Children,
+    /// Rows of the table foot, e.g. for aggregates.
+    #[prop_or_default]
+    pub footer: Children,
+    #[prop_or_default]
+    pub virtual_scroll: Option<VirtualScroll>,
+    #[prop_or_default]
+    pub selection: SelectionMode,
+    #[prop_or_default]
+    pub initial_selection: Vec<RowKey>,
+    /// Receives the selected keys in the order of the rows.
+    #[prop_or_default]
+    pub on_selection_change: Callback<Vec<RowKey>>,
+    /// Classes of the `table` element.
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub thead_class: Classes,
+    /// Classes added to each row of the table body.
+    #[prop_or_default]
+    pub row_class: Classes,
+    /// Classes added to each cell of the table body.
+    #[prop_or_default]
+    pub cell_class: Classes,
+    /// Alternating row background. Auxiliary rows, i.e. rows with a
+    /// `data-auxiliary` attribute, are skipped.
+    #[prop_or_default]
+    pub striped: bool,
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Reduced cell padding.
+    #[prop_or_default]
+    pub compact: bool,
+    /// Highlights the row under the mouse.
+    #[prop_or_default]
+    pub hoverable: bool,
+    #[prop_or_default]
+    pub sticky_header: bool,
+    #[prop_or_default]
+    pub sticky_footer: bool,
+    /// Number of leading columns that stay in view while scrolling
+    /// horizontally. The selection column is frozen along with them.
+    #[prop_or_default]
+    pub frozen_start: usize,
+    /// Number of trailing columns that stay in view while scrolling
+    /// horizontally.
+    #[prop_or_default]
+    pub frozen_end: usize,
+    /// Maximum height in pixels of the table, taller tables scroll.
+    #[prop_or_default]
+    pub max_height: Option<f64>,
+}
+
+impl Table {
+    fn window(&self, props: &TableProps, rows: &[Html]) -> Option<(VirtualScroll, Window)> {
+        props.virtual_scroll.map(|options| {
+            let window =
+                self.virtualizer
+                    .window(rows, options.row_height, options.height, options.overscan);
+            (options, window)
+        })
+    }
+
+    /// Number of leading cells per row that are frozen, including the
+    /// selection cell.
+    fn frozen_start(props: &TableProps) -> usize {
+        match (props.frozen_start, props.selection) {
+            (0, _) | (_, SelectionMode::None) => props.frozen_start,
+            (n, _) => n + 1,
+        }
+    }
+
+    fn row_keys(props: &TableProps) -> Vec<RowKey> {
+        props
+            .children
+            .iter()
+            .filter_map(|row| selection::row_key(&row).cloned())
+            .collect()
+    }
+
+    fn selection_header(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let cell = th().key("selection");
+        if props.selection != SelectionMode::Multi {
+            return cell.to_vnode();
+        }
+        let all = self.selection.all_selected(&Self::row_keys(props));
+        cell.append(
+            checkbox()
+                .node_ref(self.select_all.clone())
+                .checked(all)
+                .attr("aria-label", "select all rows")
+                .onclick(ctx.link().callback(|_| Msg::AllToggled)),
+        )
+        .to_vnode()
+    }
+
+    fn selection_row(&self, ctx: &Context<Self>, row: Html) -> Html {
+        let Some(key) = selection::row_key(&row).cloned() else {
+            return selection::prepend_child(row, td().to_vnode());
+        };
+        let selected = self.selection.is_selected(&key);
+        let cell = td().append(
+            checkbox()
+                .checked(selected)
+                .attr("aria-label", "select row")
+                .onclick(ctx.link().callback(move |e: MouseEvent| Msg::RowToggled {
+                    key: key.clone(),
+                    range: e.shift_key(),
+                })),
+        );
+        let mut row = selection::prepend_child(row, cell.to_vnode());
+        if let (true, Html::VTag(row)) = (selected, &mut row) {
+            row.add_attribute("aria-selected", "true");
+        }
+        row
+    }
 }
 
 impl Component for Table {
-    type Message = ();
+    type Message = Msg;
     type Properties = TableProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            id: style::next_id(),
+            virtualizer: Virtualizer::default(),
+            container: NodeRef::default(),
+            body: NodeRef::default(),
+            selection: Selection::new(ctx.props().initial_selection.clone()),
+            select_all: NodeRef::default(),
+            head: NodeRef::default(),
+            frozen: style::FrozenOffsets::default(),
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let columns = &ctx.props().columns;
-        let children = &ctx.props().children;
-        tag("table")
-            .class("mui-table")
+        let props = ctx.props();
+        let columns = &props.columns;
+        let rows = props.children.iter().collect::<Vec<_>>();
+        let selectable = props.selection != SelectionMode::None;
+        // computed over all rows so the stripes don't shift while scrolling
+        let stripes = match props.striped {
+            true => style::stripes(&rows),
+            false => vec![false; rows.len()],
+        };
+        let render_row = |i: usize| {
+            let row = match selectable {
+                true => self.selection_row(ctx, rows[i].clone()),
+                false => rows[i].clone(),
+            };
+            let row = match (stripes[i], row) {
+                (true, Html::VTag(mut row)) => {
+                    style::add_class(&mut row, &classes!("table-row-striped"));
+                    Html::VTag(row)
+                }
+                (_, row) => row,
+            };
+            style::style_row(row, &props.row_class, &props.cell_class)
+        };
+
+        let body = match self.window(props, &rows) {
+            None => tag("tbody").append_all((0..rows.len()).map(render_row)),
+            Some((_, window)) => {
+                let spacer = |key: &'static str, height: f64| {
+                    tr().key(key).style(format!("height: {height}px;"))
+                };
+                tag("tbody")
+                    .node_ref(self.body.clone())
+                    .append(spacer("virtual-scroll-before", window.before))
+                    .append_all(window.range.map(render_row))
+                    .append(spacer("virtual-scroll-after", window.after))
+            }
+        };
+
+        let table = tag("table")
+            .id(self.id.clone())
+            .classes(style::table_classes(props))
             .append(
-                tag("thead").append(
-                    tag("tr").append_all(
-                        columns
-                            .iter()
-                            .enumerate()
-                            .map(|(i, node)| tag("th").append(node).key(i.to_string())),
-                    ),
-                ),
+                tag("thead")
+                    .node_ref(self.head.clone())
+                    .classes(props.thead_class.clone())
+                    .append(
+                        tag("tr")
+                            .append_all(selectable.then(|| self.selection_header(ctx)))
+                            .append_all(columns.iter().enumerate().map(|(i, node)| match node {
+                                Html::VTag(ref th) if th.tag() == "th" => node,
+                                node => tag("th").append(node).key(i.to_string()).to_vnode(),
+                            })),
+                    )
+                    .append_all(props.header_rows.iter().map(|row| match selectable {
+                        true => selection::prepend_child(row, th().to_vnode()),
+                        false => row,
+                    })),
             )
-            .append(tag("tbody").append_all(children.iter()))
-            .to_vnode()
+            .append(body);
+
+        let table =
+            match props.footer.is_empty() {
+                true => table,
+                false => table.append(tag("tfoot").append_all(props.footer.iter().map(|row| {
+                    match selectable {
+                        true => selection::prepend_child(row, td().to_vnode()),
+                        false => row,
+                    }
+                }))),
+            };
+
+        let frozen = props.frozen_start > 0 || props.frozen_end > 0;
+        let table = match (props.virtual_scroll, props.max_height) {
+            (Some(options), _) => div()
+                .node_ref(self.container.clone())
+                .style(format!("overflow: auto; height: {}px;", options.height))
+                .onscroll(ctx.link().callback(|_| Msg::Scrolled))
+                .append(table)
+                .to_vnode(),
+            (None, Some(max_height)) => div()
+                .style(format!("overflow: auto; max-height: {max_height}px;"))
+                .append(table)
+                .to_vnode(),
+            (None, None) if frozen => div().style("overflow-x: auto;").append(table).to_vnode(),
+            (None, None) => table.to_vnode(),
+        };
+
+        let stylesheet = style::stylesheet(&self.id, props, &self.frozen);
+        match stylesheet.is_empty() {
+            true => table,
+            false => [tag("style").text(stylesheet).to_vnode(), table]
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        match msg {
+            Msg::Scrolled => {
+                self.virtualizer.scrolled(&self.container);
+                true
+            }
+            Msg::RowToggled { key, range } => {
+                let rows = Self::row_keys(props);
+                self.selection.toggle(props.selection, &rows, key, range);
+                props.on_selection_change.emit(self.selection.keys(&rows));
+                true
+            }
+            Msg::AllToggled => {
+                let rows = Self::row_keys(props);
+                self.selection.toggle_all(&rows);
+                props.on_selection_change.emit(self.selection.keys(&rows));
+                true
+            }
+            Msg::FrozenMeasured(frozen) => {
+                self.frozen = frozen;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if let Some(options) = ctx.props().virtual_scroll {
+            let old = old_props.children.iter().collect::<Vec<_>>();
+            let new = ctx.props().children.iter().collect::<Vec<_>>();
+            self.virtualizer
+                .rows_changed(&old, &new, options.row_height);
+        }
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        self.virtualizer.restore_scroll(&self.container);
+
+        let props = ctx.props();
+        if let Some(select_all) = self.select_all.cast::<HtmlInputElement>() {
+            let rows = Self::row_keys(props);
+            let all = self.selection.all_selected(&rows);
+            select_all.set_indeterminate(!all && self.selection.some_selected(&rows));
+        }
+
+        // the offsets of frozen columns depend on the widths of the columns
+        // before them, those are known only after rendering
+        let (start, end) = (Self::frozen_start(props), props.frozen_end);
+        let header = self
+            .head
+            .cast::<Element>()
+            .and_then(|head| head.first_element_child())
+            .filter(|_| start + end > 0 || self.frozen != Default::default());
+        if let Some(header) = header {
+            let frozen = style::FrozenOffsets::measure(&header, start, end);
+            if frozen != self.frozen {
+                ctx.link().send_message(Msg::FrozenMeasured(frozen));
+            }
+        }
+
+        let rows = props.children.iter().collect::<Vec<_>>();
+        let Some((options, window)) = self.window(props, &rows) else {
+            return;
+        };
+        if !matches!(options.row_height, RowHeight::Measured { .. }) {
+            return;
+        }
+        let Some(body) = self.body.cast::<Element>() else {
+            return;
+        };
+        // skip the spacer row before the rendered rows
+        let elements = body.children();
+        let elements = (1..elements.length()).filter_map(|i| elements.item(i));
+        if self.virtualizer.measure(&rows, window.range, elements) {
+            ctx.link().send_message(Msg::Scrolled);
+        }
     }
 }
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use super::RowFn;
+
+type Fold<R> = Rc<dyn Fn(&[&R]) -> String>;
+
+/// Aggregate of a [Column](super::Column), shown in the table foot and in
+/// group header rows of a [DataTable](super::DataTable).
+pub enum Aggregate<R> {
+    Count,
+    Sum(RowFn<R, f64>),
+    Avg(RowFn<R, f64>),
+    Min(RowFn<R, f64>),
+    Max(RowFn<R, f64>),
+    /// A custom fold over the rows, see [Aggregate::fold].
+    Fold(Fold<R>),
+}
+
+impl<R: 'static> Aggregate<R> {
+    pub fn count() -> Self {
+        Self::Count
+    }
+
+    pub fn sum(value: impl Fn(&R) -> f64 + 'static) -> Self {
+        Self::Sum(RowFn::new(value))
+    }
+
+    pub fn avg(value: impl Fn(&R) -> f64 + 'static) -> Self {
+        Self::Avg(RowFn::new(value))
+    }
+
+    pub fn min(value: impl Fn(&R) -> f64 + 'static) -> Self {
+        Self::Min(RowFn::new(value))
+    }
+
+    pub fn max(value: impl Fn(&R) -> f64 + 'static) -> Self {
+        Self::Max(RowFn::new(value))
+    }
+
+    /// Folds the rows into an accumulator starting with `init` and renders the
+    /// result with `finish`.
+    pub fn fold<A: Clone + 'static>(
+        init: A,
+        f: impl Fn(A, &R) -> A + 'static,
+        finish: impl Fn(A) -> String + 'static,
+    ) -> Self {
+        Self::Fold(Rc::new(move |rows| {
+            finish(rows.iter().fold(init.clone(), |acc, row| f(acc, row)))
+        }))
+    }
+}
+
+impl<R> Aggregate<R> {
+    /// Aggregates `rows`. Averages, minimums and maximums of no rows are
+    /// `None`.
+    ///
+    /// ```
+    /// use yew_utils::components::table::Aggregate;
+    ///
+    /// let rows = [1.0, 2.0, 6.0];
+    /// let rows = rows.iter().collect::<Vec<_>>();
+    /// let apply = |aggregate: Aggregate<f64>| aggregate.apply(&rows);
+    ///
+    /// assert_eq!(apply(Aggregate::count()).as_deref(), Some("3"));
+    /// assert_eq!(apply(Aggregate::sum(|n: &f64| *n)).as_deref(), Some("9"));
+    /// assert_eq!(apply(Aggregate::avg(|n: &f64| *n)).as_deref(), Some("3"));
+    /// assert_eq!(apply(Aggregate::max(|n: &f64| *n)).as_deref(), Some("6"));
+    /// assert_eq!(
+    ///     apply(Aggregate::fold(0, |n, row: &f64| n + (*row > 1.5) as u32, |n| format!("{n} big"))).as_deref(),
+    ///     Some("2 big")
+    /// );
+    /// assert_eq!(Aggregate::min(|n: &f64| *n).apply(&[]), None);
+    /// ```
+    pub fn apply(&self, rows: &[&R]) -> Option<String> {
+        let values = |value: &RowFn<R, f64>| -> Vec<f64> {
+            rows.iter().map(|row| value.call(row)).collect()
+        };
+        let number = |values: Vec<f64>, reduce: fn(Vec<f64>) -> f64| {
+            (!values.is_empty()).then(|| reduce(values).to_string())
+        };
+        match self {
+            Self::Count => Some(rows.len().to_string()),
+            Self::Sum(value) => Some(values(value).iter().sum::<f64>().to_string()),
+            Self::Avg(value) => number(values(value), |values| {
+                values.iter().sum::<f64>() / values.len() as f64
+            }),
+            Self::Min(value) => number(values(value), |values| {
+                values.into_iter().fold(f64::INFINITY, f64::min)
+            }),
+            Self::Max(value) => number(values(value), |values| {
+                values.into_iter().fold(f64::NEG_INFINITY, f64::max)
+            }),
+            Self::Fold(fold) => Some(fold(rows)),
+        }
+    }
+}
+
+impl<R> PartialEq for Aggregate<R> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Count, Self::Count) => true,
+            (Self::Sum(a), Self::Sum(b))
+            | (Self::Avg(a), Self::Avg(b))
+            | (Self::Min(a), Self::Min(b))
+            | (Self::Max(a), Self::Max(b)) => a == b,
+            (Self::Fold(a), Self::Fold(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Splits `order` into groups of rows with the same `key`. Groups are ordered
+/// by their first row, the rows of a group keep their order.
+///
+/// ```
+/// use yew_utils::components::table::group_indices;
+///
+/// let fruits = ["apple", "cherry", "avocado", "banana", "cranberry"];
+/// let groups = group_indices(&[0, 1, 2, 3, 4], |i| fruits[i][..1].to_string());
+/// assert_eq!(
+///     groups,
+///     vec![
+///         ("a".to_string(), vec![0, 2]),
+///         ("c".to_string(), vec![1, 4]),
+///         ("b".to_string(), vec![3]),
+///     ]
+/// );
+/// ```
+pub fn group_indices(order: &[usize], key: impl Fn(usize) -> String) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut positions = HashMap::<String, usize>::new();
+    for &index in order {
+        let key = key(index);
+        match positions.get(&key) {
+            Some(&position) => groups[position].1.push(index),
+            None => {
+                positions.insert(key.clone(), groups.len());
+                groups.push((key, vec![index]));
+            }
+        }
+    }
+    groups
+}
@@ -0,0 +1,199 @@
+use std::{cmp::Ordering, rc::Rc};
+use yew::prelude::*;
+
+use super::{Aggregate, Editor, Filter};
+
+/// A function of a table row. Compared by identity so that it can be part of
+/// component properties.
+pub struct RowFn<R, T>(Rc<dyn Fn(&R) -> T>);
+
+impl<R, T> RowFn<R, T> {
+    pub fn new(f: impl Fn(&R) -> T + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn call(&self, row: &R) -> T {
+        (self.0)(row)
+    }
+}
+
+impl<R, T> Clone for RowFn<R, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R, T> PartialEq for RowFn<R, T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<R, T, F> From<F> for RowFn<R, T>
+where
+    F: Fn(&R) -> T + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+type Comparator<R> = Rc<dyn Fn(&R, &R) -> Ordering>;
+
+/// Column definition of a [DataTable](super::DataTable).
+///
+/// A column has a header, a cell renderer and optionally a comparator. Only
+/// columns with a comparator can be sorted. The plain text [value](Self::value)
+/// of a column is used by the global search, a [Filter] adds a filter input to
+/// the column and an [Aggregate] is shown in the table foot. Cells of columns
+/// with an [Editor] can be edited.
+///
+/// ```
+/// use yew_utils::components::table::Column;
+/// use yew_utils::vdom::*;
+///
+/// struct Person { name: String, age: u32 }
+///
+/// let columns = vec![
+///     Column::new("name", |p: &Person| text(p.name.clone()).into())
+///         .sort_by_key(|p| p.name.clone()),
+///     Column::new("age", |p: &Person| text(p.age.to_string()).into())
+///         .sort_by_key(|p| p.age),
+/// ];
+/// assert!(columns.iter().all(|c| c.is_sortable()));
+/// ```
+pub struct Column<R> {
+    header: AttrValue,
+    render: RowFn<R, Html>,
+    compare: Option<Comparator<R>>,
+    value: Option<RowFn<R, String>>,
+    filter: Option<Rc<Filter<R>>>,
+    aggregate: Option<Rc<Aggregate<R>>>,
+    editor: Option<Rc<Editor<R>>>,
+}
+
+impl<R: 'static> Column<R> {
+    pub fn new(header: impl Into<AttrValue>, render: impl Fn(&R) -> Html + 'static) -> Self {
+        Self {
+            header: header.into(),
+            render: RowFn::new(render),
+            compare: None,
+            value: None,
+            filter: None,
+            aggregate: None,
+            editor: None,
+        }
+    }
+
+    /// Makes the column sortable using `compare`.
+    #[must_use]
+    pub fn compare_with(mut self, compare: impl Fn(&R, &R) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Makes the column sortable by the key extracted with `key`.
+    #[must_use]
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&R) -> K + 'static) -> Self {
+        self.compare_with(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Sets the plain text value of the cells of this column. Only columns
+    /// with a value are searched by the global search of a
+    /// [DataTable](super::DataTable), without any the search box is hidden.
+    #[must_use]
+    pub fn value(mut self, value: impl Fn(&R) -> String + 'static) -> Self {
+        self.value = Some(RowFn::new(value));
+        self
+    }
+
+    #[must_use]
+    pub fn filter(mut self, filter: Filter<R>) -> Self {
+        self.filter = Some(Rc::new(filter));
+        self
+    }
+
+    #[must_use]
+    pub fn aggregate(mut self, aggregate: Aggregate<R>) -> Self {
+        self.aggregate = Some(Rc::new(aggregate));
+        self
+    }
+
+    #[must_use]
+    pub fn editor(mut self, editor: Editor<R>) -> Self {
+        self.editor = Some(Rc::new(editor));
+        self
+    }
+}
+
+impl<R> Column<R> {
+    pub fn header(&self) -> &AttrValue {
+        &self.header
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    pub fn render(&self, row: &R) -> Html {
+        self.render.call(row)
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// The plain text value of the cell of `row`, if the column has one.
+    pub fn text(&self, row: &R) -> Option<String> {
+        self.value.as_ref().map(|value| value.call(row))
+    }
+
+    pub fn filter_def(&self) -> Option<&Filter<R>> {
+        self.filter.as_deref()
+    }
+
+    pub fn aggregate_def(&self) -> Option<&Aggregate<R>> {
+        self.aggregate.as_deref()
+    }
+
+    pub fn editor_def(&self) -> Option<&Editor<R>> {
+        self.editor.as_deref()
+    }
+
+    pub(crate) fn compare(&self, a: &R, b: &R) -> Ordering {
+        self.compare
+            .as_ref()
+            .map(|compare| compare(a, b))
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<R> Clone for Column<R> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            render: self.render.clone(),
+            compare: self.compare.clone(),
+            value: self.value.clone(),
+            filter: self.filter.clone(),
+            aggregate: self.aggregate.clone(),
+            editor: self.editor.clone(),
+        }
+    }
+}
+
+impl<R> PartialEq for Column<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && self.render == other.render
+            && self.value == other.value
+            && self.filter == other.filter
+            && self.aggregate == other.aggregate
+            && self.editor == other.editor
+            && match (&self.compare, &other.compare) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
@@ -0,0 +1,1296 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::marker::PhantomData;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, HtmlInputElement};
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+use super::aggregate::group_indices;
+use super::edit::CellEdit;
+use super::export::{self, ExportFormat};
+use super::filter::{self, filtered_indices, FilterState, FilterValue};
+use super::layout::ColumnLayout;
+use super::pagination::{self, page_count, page_range, PageRequest, Pagination, ServerPaging};
+use super::selection;
+use super::{
+    sort, Column, RowChildren, RowFn, RowKey, SelectionMode, SortDirection, SortKey, Table,
+    TableProps, VirtualScroll,
+};
+use crate::vdom::{
+    button, checkbox, comp_with, details, div, input, label, span, summary, td, th, tr,
+};
+
+/// Typed table component. Renders `rows` with the given column definitions
+/// and supports sorting by clicking on the headers of sortable columns.
+/// Clicking cycles through ascending, descending and unsorted, shift-click
+/// adds a column to the sort order.
+///
+/// With [Pagination] only one page of rows is rendered at a time. In
+/// server-side mode ([ServerPaging]) `rows` is expected to be the current page,
+/// the table asks for other pages (and sort orders) by emitting
+/// [PageRequest]s.
+///
+/// Columns with a [Filter](super::Filter) get a filter input in a second
+/// header row, `global_search` adds a search box that searches the
+/// [values](Column::value) of all columns. The [FilterState] is emitted on
+/// each change so that it can be persisted and passed back as
+/// `initial_filters`.
+///
+/// With `export` the toolbar gets buttons to download the rows as CSV or JSON,
+/// either as currently sorted and filtered or all of them. Only columns with a
+/// value are exported. With server-side paging only the rows of the current
+/// page are loaded, so only those are exported and the "all rows" option is
+/// not shown.
+///
+/// The [aggregates](super::Aggregate) of columns are shown in the table foot,
+/// computed over all filtered rows (in server-side mode over the current
+/// page). With `group_by` the rows are grouped by the [value](Column::value)
+/// of a column, each group starts with a collapsible header row that shows the
+/// aggregates of the group. Collapsing a group hides its rows on the current
+/// page.
+///
+/// Cells of columns with an [Editor](super::Editor) are edited after a double
+/// click or Enter. Enter commits the edit, Escape cancels it and Tab commits it
+/// and moves to the next editable cell (Shift+Tab to the previous one). A
+/// valid edit is emitted as [CellEdit] via `on_cell_edit`, the parent is
+/// expected to update `rows`. An edit follows its row by key when `rows`
+/// change and is dropped when the row is removed.
+///
+/// Nested rows are enabled with `row_children`: rows with children get a
+/// toggle in their first cell and child rows are indented. Children can be
+/// loaded lazily, expanding a row with [RowChildren::Lazy] emits its key via
+/// `on_load_children`. Child rows are sorted like the top level rows but not
+/// filtered, paginated or editable. With `row_detail` clicking a row shows a
+/// detail row with arbitrary content below it.
+///
+/// Columns can be resized by dragging their right border
+/// (`resizable_columns`), reordered by dragging their headers
+/// (`reorderable_columns`) and hidden from a menu in the toolbar
+/// (`column_menu`). The resulting [ColumnLayout] is emitted via
+/// `on_layout_change`.
+///
+/// Example:
+/// ```no_run
+/// use yew_utils::components::table::{Column, DataTable};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Person { name: String, age: u32 }
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// let columns = vec![
+///     Column::new("name", |p: &Person| text(p.name.clone()).into())
+///         .sort_by_key(|p| p.name.clone()),
+///     Column::new("age", |p: &Person| text(p.age.to_string()).into())
+///         .sort_by_key(|p| p.age),
+/// ];
+/// let rows = vec![
+///     Person { name: "Alice".into(), age: 42 },
+///     Person { name: "Bob".into(), age: 23 },
+/// ];
+///
+/// DataTable::render(columns, rows)
+/// # }
+/// ```
+pub struct DataTable<R> {
+    sort: Vec<SortKey>,
+    page: usize,
+    page_size: usize,
+    filters: FilterState,
+    export_all: bool,
+    layout: ColumnLayout,
+    /// Column, start x and start width of an ongoing resize.
+    resizing: Option<(usize, f64, f64)>,
+    dragging: Option<usize>,
+    collapsed_groups: BTreeSet<String>,
+    editing: Option<Editing>,
+    /// Cell to focus after rendering, the editor if it is being edited.
+    focus: Option<(usize, usize)>,
+    focus_cell: NodeRef,
+    expanded: HashSet<RowKey>,
+    open_details: HashSet<RowKey>,
+    _row: PhantomData<R>,
+}
+
+/// The cell being edited, identified by the row key and the column index, so
+/// it stays with its row when the rows change.
+struct Editing {
+    key: RowKey,
+    column: usize,
+    draft: String,
+    error: Option<String>,
+}
+
+/// The rows in view.
+struct Arranged {
+    /// Filtered rows in sort order, with the rows of a group kept together.
+    all: Vec<usize>,
+    groups: Option<Vec<(String, Vec<usize>)>>,
+    /// Rows of the current page.
+    page_rows: Vec<usize>,
+    page: usize,
+    total: usize,
+}
+
+impl<R> DataTable<R>
+where
+    R: PartialEq + 'static,
+{
+    pub fn render(columns: Vec<Column<R>>, rows: Vec<R>) -> Html {
+        comp_with::<DataTable<R>>(DataTableProps {
+            columns,
+            rows,
+            ..Default::default()
+        })
+        .to_vnode()
+    }
+}
+
+pub enum Msg {
+    HeaderClicked {
+        column: usize,
+        multi: bool,
+    },
+    PageChanged(usize),
+    PageSizeChanged(usize),
+    FilterChanged(usize, Option<FilterValue>),
+    SearchChanged(String),
+    Export(ExportFormat),
+    ExportAllToggled,
+    ResizeStarted {
+        column: usize,
+        x: f64,
+        width: f64,
+    },
+    ResizeMoved(f64),
+    ResizeEnded,
+    DragStarted(usize),
+    Dropped(usize),
+    HiddenToggled(usize),
+    GroupToggled(String),
+    EditStarted {
+        row: usize,
+        column: usize,
+    },
+    DraftChanged(String),
+    /// Commits the edit and moves on to the next (`Some(false)`) or previous
+    /// (`Some(true)`) editable cell.
+    EditCommitted {
+        move_backwards: Option<bool>,
+    },
+    EditCancelled,
+    ExpandToggled {
+        key: RowKey,
+        lazy: bool,
+    },
+    DetailToggled(RowKey),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct DataTableProps<R>
+where
+    R: PartialEq,
+{
+    pub columns: Vec<Column<R>>,
+    pub rows: Vec<R>,
+    /// Key of a row. Defaults to the index of the row in `rows`. Keys
+    /// starting with `yew-utils:` are reserved for the group, detail and
+    /// loading rows the table adds.
+    #[prop_or_default]
+    pub row_key: Option<RowFn<R, Key>>,
+    #[prop_or_default]
+    pub initial_sort: Vec<SortKey>,
+    #[prop_or_default]
+    pub on_sort_change: Callback<Vec<SortKey>>,
+    /// Client-side pagination. Also used for the page sizes of
+    /// `server_paging`.
+    #[prop_or_default]
+    pub pagination: Option<Pagination>,
+    #[prop_or_default]
+    pub server_paging: Option<ServerPaging>,
+    #[prop_or_default]
+    pub virtual_scroll: Option<VirtualScroll>,
+    /// Row selection, see [Table]. Rows are identified by `row_key`.
+    #[prop_or_default]
+    pub selection: SelectionMode,
+    #[prop_or_default]
+    pub initial_selection: Vec<RowKey>,
+    #[prop_or_default]
+    pub on_selection_change: Callback<Vec<RowKey>>,
+    /// Adds a search box over the columns with a [value](Column::value) to the
+    /// toolbar.
+    #[prop_or_default]
+    pub global_search: bool,
+    #[prop_or_default]
+    pub initial_filters: FilterState,
+    #[prop_or_default]
+    pub on_filter_change: Callback<FilterState>,
+    /// Adds export buttons to the toolbar.
+    #[prop_or_default]
+    pub export: bool,
+    /// File name of exports, without extension.
+    #[prop_or(AttrValue::Static("table"))]
+    pub export_filename: AttrValue,
+    #[prop_or_default]
+    pub resizable_columns: bool,
+    #[prop_or_default]
+    pub reorderable_columns: bool,
+    /// Adds a menu for hiding and showing columns to the toolbar.
+    #[prop_or_default]
+    pub column_menu: bool,
+    #[prop_or_default]
+    pub initial_layout: ColumnLayout,
+    #[prop_or_default]
+    pub on_layout_change: Callback<ColumnLayout>,
+    #[prop_or_default]
+    pub on_cell_edit: Callback<CellEdit<R>>,
+    /// The children of a row, for nested rows. Child rows are identified by
+    /// `row_key` or else by their position below their parent.
+    #[prop_or_default]
+    pub row_children: Option<RowFn<R, RowChildren<R>>>,
+    /// Emitted with the key of an expanded row whose children are
+    /// [RowChildren::Lazy].
+    #[prop_or_default]
+    pub on_load_children: Callback<RowKey>,
+    /// Content of the detail row that clicking a row shows below it.
+    #[prop_or_default]
+    pub row_detail: Option<RowFn<R, Html>>,
+    /// Index of the column to group the rows by. The column needs a value.
+    #[prop_or_default]
+    pub group_by: Option<usize>,
+    /// Classes, variants and sticky parts, see [TableProps]. Frozen columns
+    /// are counted in display order.
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub thead_class: Classes,
+    #[prop_or_default]
+    pub row_class: Classes,
+    #[prop_or_default]
+    pub cell_class: Classes,
+    #[prop_or_default]
+    pub striped: bool,
+    #[prop_or_default]
+    pub bordered: bool,
+    #[prop_or_default]
+    pub compact: bool,
+    #[prop_or_default]
+    pub hoverable: bool,
+    #[prop_or_default]
+    pub sticky_header: bool,
+    #[prop_or_default]
+    pub sticky_footer: bool,
+    #[prop_or_default]
+    pub frozen_start: usize,
+    #[prop_or_default]
+    pub frozen_end: usize,
+    #[prop_or_default]
+    pub max_height: Option<f64>,
+}
+
+impl<R> Default for DataTableProps<R>
+where
+    R: PartialEq,
+{
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            row_key: None,
+            initial_sort: Vec::new(),
+            on_sort_change: Callback::noop(),
+            pagination: None,
+            server_paging: None,
+            virtual_scroll: None,
+            selection: SelectionMode::None,
+            initial_selection: Vec::new(),
+            on_selection_change: Callback::noop(),
+            global_search: false,
+            initial_filters: FilterState::default(),
+            on_filter_change: Callback::noop(),
+            export: false,
+            export_filename: AttrValue::Static("table"),
+            resizable_columns: false,
+            reorderable_columns: false,
+            column_menu: false,
+            initial_layout: ColumnLayout::default(),
+            on_layout_change: Callback::noop(),
+            on_cell_edit: Callback::noop(),
+            row_children: None,
+            on_load_children: Callback::noop(),
+            row_detail: None,
+            group_by: None,
+            class: Classes::new(),
+            thead_class: Classes::new(),
+            row_class: Classes::new(),
+            cell_class: Classes::new(),
+            striped: false,
+            bordered: false,
+            compact: false,
+            hoverable: false,
+            sticky_header: false,
+            sticky_footer: false,
+            frozen_start: 0,
+            frozen_end: 0,
+            max_height: None,
+        }
+    }
+}
+
+impl<R> DataTable<R>
+where
+    R: PartialEq + 'static,
+{
+    fn header_cell(&self, ctx: &Context<Self>, index: usize, column: &Column<R>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let mut cell = th().key(index);
+        let mut style = Vec::new();
+
+        if let Some(width) = self.layout.widths.get(&index) {
+            style.push(format!("width: {width}px; min-width: {width}px;"));
+        }
+
+        if props.reorderable_columns {
+            cell = cell
+                .attr("draggable", "true")
+                .ondragstart(link.callback(move |e: DragEvent| {
+                    if let Some(data) = e.data_transfer() {
+                        // firefox only starts dragging with data
+                        let _ = data.set_data("text/plain", &index.to_string());
+                    }
+                    Msg::DragStarted(index)
+                }))
+                .ondragover(|e: DragEvent| e.prevent_default())
+                .ondrop(link.callback(move |e: DragEvent| {
+                    e.prevent_default();
+                    Msg::Dropped(index)
+                }));
+        }
+
+        let mut indicator = None;
+        if column.is_sortable() {
+            let pos = self.sort.iter().position(|key| key.column == index);
+            let (aria_sort, arrow) = match pos.map(|pos| self.sort[pos].direction) {
+                Some(SortDirection::Ascending) => ("ascending", "▲"),
+                Some(SortDirection::Descending) => ("descending", "▼"),
+                None => ("none", ""),
+            };
+            let text = match pos {
+                Some(pos) if self.sort.len() > 1 => format!(" {arrow}{}", pos + 1),
+                Some(_) => format!(" {arrow}"),
+                None => String::new(),
+            };
+            indicator = Some(span().class("sort-indicator").text(text));
+            style.push("cursor: pointer; user-select: none;".to_string());
+            cell =
+                cell.attr("aria-sort", aria_sort)
+                    .onclick(link.callback(move |e: MouseEvent| Msg::HeaderClicked {
+                        column: index,
+                        multi: e.shift_key(),
+                    }));
+        }
+
+        let mut handle = None;
+        if props.resizable_columns {
+            style.push("position: relative;".to_string());
+            handle = Some(self.resize_handle(ctx, index));
+        }
+
+        cell.style(style.join(" "))
+            .text(column.header().clone())
+            .append_all(indicator)
+            .append_all(handle)
+            .to_vnode()
+    }
+
+    fn resize_handle(&self, ctx: &Context<Self>, index: usize) -> Html {
+        let link = ctx.link();
+        span()
+            .class("column-resize-handle")
+            .attr("aria-hidden", "true")
+            .style(
+                "position: absolute; top: 0; right: 0; bottom: 0; width: 6px; cursor: col-resize;",
+            )
+            // don't start dragging or sorting the column
+            .onmousedown(|e: MouseEvent| e.prevent_default())
+            .onclick(|e: MouseEvent| e.stop_propagation())
+            .onpointerdown(link.callback(move |e: PointerEvent| {
+                let handle = e.target_unchecked_into::<Element>();
+                let _ = handle.set_pointer_capture(e.pointer_id());
+                let width = handle
+                    .parent_element()
+                    .map(|th| th.get_bounding_client_rect().width())
+                    .unwrap_or_default();
+                Msg::ResizeStarted {
+                    column: index,
+                    x: e.client_x() as f64,
+                    width,
+                }
+            }))
+            .onpointermove(link.callback(|e: PointerEvent| Msg::ResizeMoved(e.client_x() as f64)))
+            .onpointerup(link.callback(|_| Msg::ResizeEnded))
+            .to_vnode()
+    }
+
+    fn column_menu(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let count = props.columns.len();
+        let last_visible = self.layout.visible(count).len() <= 1;
+        let items = self.layout.ordered(count).into_iter().map(|i| {
+            let visible = !self.layout.hidden.contains(&i);
+            let mut toggle = checkbox()
+                .checked(visible)
+                .onclick(ctx.link().callback(move |_| Msg::HiddenToggled(i)));
+            if visible && last_visible {
+                toggle = toggle.attr("disabled", "disabled");
+            }
+            label()
+                .style("display: block;")
+                .append(toggle)
+                .text(format!(" {}", props.columns[i].header()))
+        });
+        details()
+            .class("table-column-menu")
+            .append(summary().text("Columns"))
+            .append_all(items)
+            .to_vnode()
+    }
+
+    /// The visible columns in display order, paired with their index.
+    fn visible_columns<'a>(&self, props: &'a DataTableProps<R>) -> Vec<(usize, &'a Column<R>)> {
+        self.layout
+            .visible(props.columns.len())
+            .into_iter()
+            .map(|i| (i, &props.columns[i]))
+            .collect()
+    }
+
+    fn layout_changed(&self, ctx: &Context<Self>) {
+        ctx.props().on_layout_change.emit(self.layout.clone());
+    }
+
+    fn group_column(props: &DataTableProps<R>) -> Option<&Column<R>> {
+        props
+            .group_by
+            .and_then(|column| props.columns.get(column))
+            .filter(|column| column.has_value())
+    }
+
+    /// One cell per visible column with the aggregate of `rows`, if the column
+    /// has one.
+    fn aggregate_cells<'a>(
+        visible: &'a [(usize, &Column<R>)],
+        rows: &'a [&R],
+    ) -> impl Iterator<Item = Option<Html>> + 'a {
+        visible.iter().map(|(_, column)| {
+            column
+                .aggregate_def()
+                .and_then(|aggregate| aggregate.apply(rows))
+                .map(|value| span().class("table-aggregate").text(value).to_vnode())
+        })
+    }
+
+    fn footer(visible: &[(usize, &Column<R>)], rows: &[&R]) -> Option<Html> {
+        if visible
+            .iter()
+            .all(|(_, column)| column.aggregate_def().is_none())
+        {
+            return None;
+        }
+        let cells = Self::aggregate_cells(visible, rows).map(|value| td().append_all(value));
+        Some(
+            tr().key("aggregates")
+                .class("table-aggregates")
+                .append_all(cells)
+                .to_vnode(),
+        )
+    }
+
+    fn group_row(
+        &self,
+        ctx: &Context<Self>,
+        visible: &[(usize, &Column<R>)],
+        value: &str,
+        rows: &[&R],
+    ) -> Html {
+        let collapsed = self.collapsed_groups.contains(value);
+        let toggle = {
+            let value = value.to_string();
+            button()
+                .attr("type", "button")
+                .attr("aria-expanded", (!collapsed).to_string())
+                .attr("aria-label", format!("toggle group {value}"))
+                .onclick(
+                    ctx.link()
+                        .callback(move |_| Msg::GroupToggled(value.clone())),
+                )
+                .text(if collapsed { "▸" } else { "▾" })
+                .to_vnode()
+        };
+        let cells =
+            Self::aggregate_cells(visible, rows)
+                .enumerate()
+                .map(|(i, aggregate)| match i {
+                    0 => td()
+                        .append(toggle.clone())
+                        .text(format!(" {value} ({}) ", rows.len()))
+                        .append_all(aggregate),
+                    _ => td().append_all(aggregate),
+                });
+        tr().key(format!("yew-utils:group:{value}"))
+            .class("table-group")
+            .attr("data-selectable", "false")
+            .attr("data-auxiliary", "true")
+            .append_all(cells)
+            .to_vnode()
+    }
+
+    fn editable_cell(
+        &self,
+        ctx: &Context<Self>,
+        index: usize,
+        key: &RowKey,
+        column_index: usize,
+        column: &Column<R>,
+    ) -> Html {
+        let link = ctx.link();
+        let editing = self
+            .editing
+            .as_ref()
+            .filter(|editing| editing.key == *key && editing.column == column_index);
+        let is_editing = editing.is_some();
+        let mut cell = td()
+            .class("table-editable")
+            .attr("tabindex", "0")
+            .onkeydown(link.batch_callback(move |e: KeyboardEvent| {
+                let msg = match (e.key().as_str(), is_editing) {
+                    ("Enter", false) => Msg::EditStarted {
+                        row: index,
+                        column: column_index,
+                    },
+                    ("Enter", true) => Msg::EditCommitted {
+                        move_backwards: None,
+                    },
+                    ("Tab", true) => Msg::EditCommitted {
+                        move_backwards: Some(e.shift_key()),
+                    },
+                    ("Escape", true) => Msg::EditCancelled,
+                    _ => return None,
+                };
+                e.prevent_default();
+                e.stop_propagation();
+                Some(msg)
+            }));
+        if self.focus == Some((index, column_index)) {
+            cell = cell.node_ref(self.focus_cell.clone());
+        }
+
+        match (editing, column.editor_def()) {
+            (Some(editing), Some(editor)) => cell
+                .append(editor.input(
+                    &editing.draft,
+                    editing.error.is_some(),
+                    link.callback(Msg::DraftChanged),
+                ))
+                .append_all(editing.error.as_ref().map(|error| {
+                    span()
+                        .class("table-cell-error")
+                        .attr("role", "alert")
+                        .text(error.clone())
+                }))
+                .to_vnode(),
+            _ => cell
+                .ondblclick(link.callback(move |_| Msg::EditStarted {
+                    row: index,
+                    column: column_index,
+                }))
+                .append(column.render(&ctx.props().rows[index]))
+                .to_vnode(),
+        }
+    }
+
+    /// Renders `row`, its detail row and its expanded descendants into `rows`.
+    /// Only top level rows have an `index` and can be edited.
+    #[allow(clippy::too_many_arguments)]
+    fn tree_rows(
+        &self,
+        ctx: &Context<Self>,
+        visible: &[(usize, &Column<R>)],
+        row: &R,
+        index: Option<usize>,
+        key: RowKey,
+        depth: usize,
+        rows: &mut Vec<Html>,
+    ) {
+        let props = ctx.props();
+        let link = ctx.link();
+        let children = props
+            .row_children
+            .as_ref()
+            .map(|children| children.call(row))
+            .unwrap_or_default();
+        let expanded = self.expanded.contains(&key);
+
+        let mut cells = visible
+            .iter()
+            .map(|&(i, column)| match (index, column.editor_def()) {
+                (Some(index), Some(_)) => self.editable_cell(ctx, index, &key, i, column),
+                _ => td().append(column.render(row)).to_vnode(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut row_tag = tr().key(key.clone());
+        if props.row_children.is_some() {
+            let indent = span().style(format!(
+                "display: inline-block; width: {}em;",
+                depth as f64 * 1.25
+            ));
+            let toggle = match children.is_none() {
+                true => span()
+                    .style("display: inline-block; width: 1.5em;")
+                    .to_vnode(),
+                false => {
+                    let key = key.clone();
+                    let lazy = children == RowChildren::Lazy;
+                    button()
+                        .attr("type", "button")
+                        .attr("aria-expanded", expanded.to_string())
+                        .attr("aria-label", "toggle children")
+                        .style("width: 1.5em;")
+                        .onclick(link.callback(move |_| Msg::ExpandToggled {
+                            key: key.clone(),
+                            lazy,
+                        }))
+                        .text(if expanded { "▾" } else { "▸" })
+                        .to_vnode()
+                }
+            };
+            if let Some(first) = cells.first_mut() {
+                let cell = std::mem::take(first);
+                let cell = selection::prepend_child(cell, toggle);
+                *first = selection::prepend_child(cell, indent.to_vnode());
+            }
+            row_tag = row_tag.attr("aria-level", (depth + 1).to_string());
+        }
+
+        if props.row_detail.is_some() {
+            let key = key.clone();
+            row_tag = row_tag
+                .class("table-row-expandable")
+                .attr(
+                    "aria-expanded",
+                    self.open_details.contains(&key).to_string(),
+                )
+                .onclick(link.batch_callback(move |e: MouseEvent| {
+                    // clicks on inputs, buttons and links in the row are theirs
+                    let target = e.target_dyn_into::<Element>()?;
+                    let interactive = target
+                        .closest("a, button, input, select, textarea, label")
+                        .ok()
+                        .flatten();
+                    interactive
+                        .is_none()
+                        .then(|| Msg::DetailToggled(key.clone()))
+                }));
+        }
+        rows.push(row_tag.append_all(cells).to_vnode());
+
+        let full_width = |key: String, class: &'static str| {
+            tr().key(key)
+                .class(class)
+                .attr("data-selectable", "false")
+                .attr("data-auxiliary", "true")
+                .append(td().attr("colspan", visible.len().to_string()))
+        };
+
+        if let Some(detail) = &props.row_detail {
+            if self.open_details.contains(&key) {
+                let detail_row = full_width(format!("yew-utils:detail:{key}"), "table-row-detail");
+                rows.push(detail_row.append(detail.call(row)).to_vnode());
+            }
+        }
+
+        if !expanded {
+            return;
+        }
+        match children {
+            RowChildren::None => {}
+            RowChildren::Lazy => rows.push(
+                full_width(format!("yew-utils:loading:{key}"), "table-loading")
+                    .text("Loading…")
+                    .to_vnode(),
+            ),
+            RowChildren::Loaded(children) => {
+                let mut order = (0..children.len()).collect::<Vec<_>>();
+                sort::sort_indices(&mut order, &children, &props.columns, &self.sort);
+                for i in order {
+                    let child = &children[i];
+                    let child_key = match &props.row_key {
+                        Some(row_key) => row_key.call(child),
+                        None => format!("{key}/{i}").into(),
+                    };
+                    self.tree_rows(ctx, visible, child, None, child_key, depth + 1, rows);
+                }
+            }
+        }
+    }
+
+    fn start_edit(&mut self, props: &DataTableProps<R>, row: usize, column: usize) {
+        let Some(editor) = props.columns.get(column).and_then(Column::editor_def) else {
+            return;
+        };
+        self.editing = Some(Editing {
+            key: Self::row_key(props, row),
+            column,
+            draft: editor.draft(&props.rows[row]),
+            error: None,
+        });
+        self.focus = Some((row, column));
+    }
+
+    /// The editable cell after (or before) `cell` among the rendered rows and
+    /// visible columns.
+    fn next_editable_cell(
+        &self,
+        props: &DataTableProps<R>,
+        cell: (usize, usize),
+        backwards: bool,
+    ) -> Option<(usize, usize)> {
+        let columns = self
+            .visible_columns(props)
+            .into_iter()
+            .filter(|(_, column)| column.editor_def().is_some())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let group_column = Self::group_column(props);
+        let cells = self
+            .arranged(props)
+            .page_rows
+            .into_iter()
+            .filter(|&row| {
+                group_column
+                    .and_then(|column| column.text(&props.rows[row]))
+                    .is_none_or(|group| !self.collapsed_groups.contains(&group))
+            })
+            .flat_map(|row| columns.iter().map(move |&column| (row, column)))
+            .collect::<Vec<_>>();
+        let pos = cells.iter().position(|&c| c == cell)?;
+        match backwards {
+            true => pos.checked_sub(1).map(|pos| cells[pos]),
+            false => cells.get(pos + 1).copied(),
+        }
+    }
+
+    /// Filters, sorts, groups and paginates the rows.
+    fn arranged(&self, props: &DataTableProps<R>) -> Arranged {
+        let mut all = self.ordered(props);
+
+        // the rows of a group are kept together, starting at the position of
+        // the first one
+        let groups = Self::group_column(props)
+            .map(|column| group_indices(&all, |i| column.text(&props.rows[i]).unwrap_or_default()));
+        if let Some(groups) = &groups {
+            all = groups.iter().flat_map(|(_, rows)| rows.clone()).collect();
+        }
+
+        let server = props.server_paging.as_ref();
+        let total = server.map(|server| server.total).unwrap_or(all.len());
+        let page = self.page.min(page_count(total, self.page_size) - 1);
+        let page_rows = match Self::pagination(props).is_some() && server.is_none() {
+            true => all[page_range(page, self.page_size, total)].to_vec(),
+            false => all.clone(),
+        };
+        Arranged {
+            all,
+            groups,
+            page_rows,
+            page,
+            total,
+        }
+    }
+
+    fn pagination(props: &DataTableProps<R>) -> Option<Pagination> {
+        props
+            .pagination
+            .clone()
+            .or_else(|| props.server_paging.as_ref().map(|_| Pagination::default()))
+    }
+
+    /// Moves back to the last page if the current one is past it, e.g. after
+    /// the rows, the server total or the page size shrank. Returns whether the
+    /// page changed.
+    fn clamp_page(&mut self, props: &DataTableProps<R>) -> bool {
+        let total = match &props.server_paging {
+            Some(server) => server.total,
+            None => self.ordered(props).len(),
+        };
+        let last = page_count(total, self.page_size) - 1;
+        let clamped = self.page > last;
+        self.page = self.page.min(last);
+        clamped
+    }
+
+    fn filters_changed(&mut self, ctx: &Context<Self>) {
+        self.page = 0;
+        ctx.props().on_filter_change.emit(self.filters.clone());
+        self.request_page(ctx);
+    }
+
+    fn request_page(&self, ctx: &Context<Self>) {
+        if let Some(server) = &ctx.props().server_paging {
+            server.on_page_request.emit(PageRequest {
+                page: self.page,
+                page_size: self.page_size,
+                sort: self.sort.clone(),
+                filters: self.filters.clone(),
+            });
+        }
+    }
+
+    fn filter_row(&self, ctx: &Context<Self>) -> Option<Html> {
+        let props = ctx.props();
+        if props
+            .columns
+            .iter()
+            .all(|column| column.filter_def().is_none())
+        {
+            return None;
+        }
+        let cells = self.visible_columns(props).into_iter().map(|(i, column)| {
+            let cell = th().key(i).class("table-filter");
+            match column.filter_def() {
+                Some(def) => cell.append(filter::filter_input(
+                    def,
+                    self.filters.columns.get(&i),
+                    ctx.link()
+                        .callback(move |value| Msg::FilterChanged(i, value)),
+                )),
+                None => cell,
+            }
+        });
+        Some(tr().key("filters").append_all(cells).to_vnode())
+    }
+
+    fn toolbar(&self, ctx: &Context<Self>) -> Option<Html> {
+        let props = ctx.props();
+        let global_search = props.global_search && props.columns.iter().any(Column::has_value);
+        if !global_search && !props.export && !props.column_menu {
+            return None;
+        }
+
+        let search = global_search.then(|| {
+            input()
+                .type_search()
+                .attr("placeholder", "search")
+                .attr("aria-label", "search")
+                .value(self.filters.search.clone())
+                .oninput(ctx.link().callback(|e: InputEvent| {
+                    Msg::SearchChanged(e.target_unchecked_into::<HtmlInputElement>().value())
+                }))
+                .to_vnode()
+        });
+
+        let export = props.export.then(|| {
+            let export_button = |text: &'static str, format: ExportFormat| {
+                button()
+                    .attr("type", "button")
+                    .onclick(ctx.link().callback(move |_| Msg::Export(format)))
+                    .text(text)
+            };
+            span()
+                .class("table-export")
+                .append_all([
+                    export_button("Export CSV", ExportFormat::Csv),
+                    export_button("Export JSON", ExportFormat::Json),
+                ])
+                .append_all(props.server_paging.is_none().then(|| {
+                    label()
+                        .append(
+                            checkbox()
+                                .checked(self.export_all)
+                                .onclick(ctx.link().callback(|_| Msg::ExportAllToggled)),
+                        )
+                        .text(" all rows")
+                }))
+                .to_vnode()
+        });
+
+        Some(
+            div()
+                .class("table-toolbar")
+                .append_all(search.into_iter().chain(export))
+                .append_all(props.column_menu.then(|| self.column_menu(ctx)))
+                .to_vnode(),
+        )
+    }
+
+    /// Indexes of the filtered rows in sort order.
+    fn ordered(&self, props: &DataTableProps<R>) -> Vec<usize> {
+        if props.server_paging.is_some() {
+            return (0..props.rows.len()).collect();
+        }
+        let mut order = filtered_indices(&props.rows, &props.columns, &self.filters);
+        sort::sort_indices(&mut order, &props.rows, &props.columns, &self.sort);
+        order
+    }
+
+    fn export(&self, props: &DataTableProps<R>, format: ExportFormat) {
+        let columns = self
+            .visible_columns(props)
+            .into_iter()
+            .map(|(_, column)| column.clone())
+            .collect::<Vec<_>>();
+        let content = match self.export_all && props.server_paging.is_none() {
+            true => export::export(format, &columns, &props.rows),
+            false => {
+                let rows = self.ordered(props).into_iter().map(|i| &props.rows[i]);
+                export::export(format, &columns, rows)
+            }
+        };
+        let filename = format!("{}.{}", props.export_filename, format.extension());
+        if let Err(err) = export::download(&filename, format.mime_type(), &content) {
+            web_sys::console::error_2(&"table export failed".into(), &err);
+        }
+    }
+
+    fn row_key(props: &DataTableProps<R>, index: usize) -> Key {
+        match &props.row_key {
+            Some(row_key) => row_key.call(&props.rows[index]),
+            None => index.into(),
+        }
+    }
+
+    /// Index in `rows` of the row with `key`.
+    fn row_index(props: &DataTableProps<R>, key: &RowKey) -> Option<usize> {
+        (0..props.rows.len()).find(|&index| Self::row_key(props, index) == *key)
+    }
+}
+
+impl<R> Component for DataTable<R>
+where
+    R: PartialEq + 'static,
+{
+    type Message = Msg;
+    type Properties = DataTableProps<R>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let page_size = Self::pagination(ctx.props())
+            .map(|pagination| pagination.page_size)
+            .unwrap_or_default();
+        Self {
+            sort: ctx.props().initial_sort.clone(),
+            page: 0,
+            page_size,
+            filters: ctx.props().initial_filters.clone(),
+            export_all: false,
+            layout: ctx.props().initial_layout.clone(),
+            resizing: None,
+            dragging: None,
+            collapsed_groups: BTreeSet::new(),
+            editing: None,
+            focus: None,
+            focus_cell: NodeRef::default(),
+            expanded: HashSet::new(),
+            open_details: HashSet::new(),
+            _row: PhantomData,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        let visible = self.visible_columns(props);
+        let columns = visible
+            .iter()
+            .map(|&(i, column)| self.header_cell(ctx, i, column))
+            .collect::<Vec<_>>();
+
+        let Arranged {
+            all,
+            groups,
+            page_rows,
+            page,
+            total,
+        } = self.arranged(props);
+        let all_rows = all.iter().map(|&i| &props.rows[i]).collect::<Vec<_>>();
+        let footer = Self::footer(&visible, &all_rows);
+
+        let data_rows = |rows: &mut Vec<Html>, index: usize| {
+            let key = Self::row_key(props, index);
+            self.tree_rows(ctx, &visible, &props.rows[index], Some(index), key, 0, rows);
+        };
+
+        let rows = match &groups {
+            None => {
+                let mut rows = Vec::new();
+                for index in page_rows {
+                    data_rows(&mut rows, index);
+                }
+                rows
+            }
+            Some(groups) => {
+                let group_of = groups
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(group, (_, rows))| rows.iter().map(move |&row| (row, group)))
+                    .collect::<HashMap<_, _>>();
+                let mut rows = Vec::new();
+                let mut current = None;
+                for index in page_rows {
+                    let group = group_of[&index];
+                    let (value, indices) = &groups[group];
+                    if current != Some(group) {
+                        current = Some(group);
+                        let group_rows =
+                            indices.iter().map(|&i| &props.rows[i]).collect::<Vec<_>>();
+                        rows.push(self.group_row(ctx, &visible, value, &group_rows));
+                    }
+                    if !self.collapsed_groups.contains(value) {
+                        data_rows(&mut rows, index);
+                    }
+                }
+                rows
+            }
+        };
+
+        let table = comp_with::<Table>(TableProps {
+            columns: Children::new(columns),
+            children: Children::new(rows),
+            header_rows: Children::new(self.filter_row(ctx).into_iter().collect()),
+            footer: Children::new(footer.into_iter().collect()),
+            virtual_scroll: props.virtual_scroll,
+            selection: props.selection,
+            initial_selection: props.initial_selection.clone(),
+            on_selection_change: props.on_selection_change.clone(),
+            class: props.class.clone(),
+            thead_class: props.thead_class.clone(),
+            row_class: props.row_class.clone(),
+            cell_class: props.cell_class.clone(),
+            striped: props.striped,
+            bordered: props.bordered,
+            compact: props.compact,
+            hoverable: props.hoverable,
+            sticky_header: props.sticky_header,
+            sticky_footer: props.sticky_footer,
+            frozen_start: props.frozen_start,
+            frozen_end: props.frozen_end,
+            max_height: props.max_height,
+        })
+        .to_vnode();
+
+        let toolbar = self.toolbar(ctx);
+        let pager = Self::pagination(props).map(|pagination| {
+            pagination::pager(
+                &pagination.page_sizes,
+                page,
+                self.page_size,
+                total,
+                ctx.link().callback(Msg::PageChanged),
+                ctx.link().callback(Msg::PageSizeChanged),
+            )
+        });
+
+        toolbar.into_iter().chain([table]).chain(pager).collect()
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        // an edit is kept as long as its row and its editable column exist
+        let keep_editing = self.editing.as_ref().is_some_and(|editing| {
+            let editable = props
+                .columns
+                .get(editing.column)
+                .and_then(Column::editor_def);
+            editable.is_some() && Self::row_index(props, &editing.key).is_some()
+        });
+        if !keep_editing {
+            self.editing = None;
+        }
+        if self.clamp_page(props) {
+            self.request_page(ctx);
+        }
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            self.request_page(ctx);
+        }
+        if self.focus.take().is_some() {
+            let cell = self.focus_cell.cast::<Element>();
+            let target = match self.editing {
+                Some(_) => {
+                    cell.and_then(|cell| cell.query_selector("input, select").ok().flatten())
+                }
+                None => cell,
+            };
+            if let Some(target) = target.and_then(|target| target.dyn_into::<HtmlElement>().ok()) {
+                let _ = target.focus();
+            }
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::HeaderClicked { column, multi } => {
+                self.sort = sort::toggle_sort(&self.sort, column, multi);
+                self.page = 0;
+                ctx.props().on_sort_change.emit(self.sort.clone());
+                self.request_page(ctx);
+                true
+            }
+            Msg::PageChanged(page) => {
+                self.page = page;
+                self.request_page(ctx);
+                true
+            }
+            Msg::PageSizeChanged(page_size) => {
+                // keep the first row of the current page visible
+                self.page = self.page * self.page_size / page_size.max(1);
+                self.page_size = page_size;
+                self.clamp_page(ctx.props());
+                self.request_page(ctx);
+                true
+            }
+            Msg::FilterChanged(column, value) => {
+                match value {
+                    Some(value) => self.filters.columns.insert(column, value),
+                    None => self.filters.columns.remove(&column),
+                };
+                self.filters_changed(ctx);
+                true
+            }
+            Msg::SearchChanged(search) => {
+                self.filters.search = search;
+                self.filters_changed(ctx);
+                true
+            }
+            Msg::Export(format) => {
+                self.export(ctx.props(), format);
+                false
+            }
+            Msg::ExportAllToggled => {
+                self.export_all = !self.export_all;
+                true
+            }
+            Msg::ResizeStarted { column, x, width } => {
+                self.resizing = Some((column, x, width));
+                false
+            }
+            Msg::ResizeMoved(x) => match self.resizing {
+                Some((column, start_x, start_width)) => {
+                    let width = (start_width + x - start_x).max(20.0);
+                    self.layout.widths.insert(column, width);
+                    true
+                }
+                None => false,
+            },
+            Msg::ResizeEnded => {
+                if self.resizing.take().is_some() {
+                    self.layout_changed(ctx);
+                }
+                false
+            }
+            Msg::DragStarted(column) => {
+                self.dragging = Some(column);
+                false
+            }
+            Msg::Dropped(target) => match self.dragging.take() {
+                Some(column) if column != target => {
+                    let count = ctx.props().columns.len();
+                    self.layout.move_column(count, column, target);
+                    self.layout_changed(ctx);
+                    true
+                }
+                _ => false,
+            },
+            Msg::HiddenToggled(column) => {
+                self.layout.toggle_hidden(column);
+                self.layout_changed(ctx);
+                true
+            }
+            Msg::GroupToggled(group) => {
+                if !self.collapsed_groups.remove(&group) {
+                    self.collapsed_groups.insert(group);
+                }
+                true
+            }
+            Msg::EditStarted { row, column } => {
+                self.start_edit(ctx.props(), row, column);
+                true
+            }
+            Msg::DraftChanged(draft) => match &mut self.editing {
+                Some(editing) => {
+                    editing.draft = draft;
+                    editing.error = None;
+                    true
+                }
+                None => false,
+            },
+            Msg::EditCommitted { move_backwards } => {
+                let props = ctx.props();
+                let Some(editing) = &mut self.editing else {
+                    return false;
+                };
+                let editor = props
+                    .columns
+                    .get(editing.column)
+                    .and_then(Column::editor_def);
+                let (Some(index), Some(editor)) = (Self::row_index(props, &editing.key), editor)
+                else {
+                    self.editing = None;
+                    return true;
+                };
+                let row = &props.rows[index];
+                if editing.draft != editor.draft(row) {
+                    match editor.commit(row, &editing.draft) {
+                        Ok(row) => props.on_cell_edit.emit(CellEdit {
+                            key: editing.key.clone(),
+                            index,
+                            column: editing.column,
+                            row,
+                        }),
+                        Err(error) => {
+                            editing.error = Some(error);
+                            return true;
+                        }
+                    }
+                }
+                let cell = (index, editing.column);
+                self.editing = None;
+                self.focus = Some(cell);
+                let next = move_backwards
+                    .and_then(|backwards| self.next_editable_cell(props, cell, backwards));
+                if let Some((row, column)) = next {
+                    self.start_edit(props, row, column);
+                }
+                true
+            }
+            Msg::EditCancelled => {
+                let props = ctx.props();
+                self.focus = self.editing.take().and_then(|editing| {
+                    Self::row_index(props, &editing.key).map(|row| (row, editing.column))
+                });
+                true
+            }
+            Msg::ExpandToggled { key, lazy } => {
+                if !self.expanded.remove(&key) {
+                    self.expanded.insert(key.clone());
+                    if lazy {
+                        ctx.props().on_load_children.emit(key);
+                    }
+                }
+                true
+            }
+            Msg::DetailToggled(key) => {
+                if !self.open_details.remove(&key) {
+                    self.open_details.insert(key);
+                }
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt::Display;
+use std::rc::Rc;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::{RowFn, RowKey};
+use crate::components::drop_down::{DropDown, DropDownProps};
+use crate::vdom::{checkbox, comp_with, input};
+
+type Setter<R, T> = Rc<dyn Fn(&R, T) -> Result<R, String>>;
+
+/// Editor of the cells of a [Column](super::Column). `get` reads the value
+/// from a row, `set` validates an edited value and returns the updated row or
+/// an error message that is shown next to the editor.
+///
+/// ```
+/// use yew_utils::components::table::{Column, Editor};
+/// use yew_utils::vdom::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Item { name: String, qty: u32 }
+///
+/// let qty = Column::new("qty", |i: &Item| text(i.qty.to_string()).into()).editor(
+///     Editor::number(
+///         |i: &Item| i.qty as f64,
+///         |i, qty| match qty >= 0.0 && qty.fract() == 0.0 {
+///             true => Ok(Item { qty: qty as u32, ..i.clone() }),
+///             false => Err("not a whole number".to_string()),
+///         },
+///     ),
+/// );
+/// assert!(qty.editor_def().is_some());
+/// ```
+pub enum Editor<R> {
+    Text {
+        get: RowFn<R, String>,
+        set: Setter<R, String>,
+    },
+    Number {
+        get: RowFn<R, f64>,
+        set: Setter<R, f64>,
+    },
+    Checkbox {
+        get: RowFn<R, bool>,
+        set: Setter<R, bool>,
+    },
+    /// A drop down of `options`, e.g. the variants of an enum.
+    Select {
+        get: RowFn<R, String>,
+        options: Vec<String>,
+        set: Setter<R, String>,
+    },
+}
+
+impl<R: 'static> Editor<R> {
+    pub fn text(
+        get: impl Fn(&R) -> String + 'static,
+        set: impl Fn(&R, String) -> Result<R, String> + 'static,
+    ) -> Self {
+        Self::Text {
+            get: RowFn::new(get),
+            set: Rc::new(set),
+        }
+    }
+
+    pub fn number(
+        get: impl Fn(&R) -> f64 + 'static,
+        set: impl Fn(&R, f64) -> Result<R, String> + 'static,
+    ) -> Self {
+        Self::Number {
+            get: RowFn::new(get),
+            set: Rc::new(set),
+        }
+    }
+
+    pub fn checkbox(
+        get: impl Fn(&R) -> bool + 'static,
+        set: impl Fn(&R, bool) -> Result<R, String> + 'static,
+    ) -> Self {
+        Self::Checkbox {
+            get: RowFn::new(get),
+            set: Rc::new(set),
+        }
+    }
+
+    /// A drop down of `options`, which are shown with their [Display]
+    /// implementation.
+    pub fn select<T>(
+        options: Vec<T>,
+        get: impl Fn(&R) -> T + 'static,
+        set: impl Fn(&R, T) -> Result<R, String> + 'static,
+    ) -> Self
+    where
+        T: Display + Clone + 'static,
+    {
+        let labels = options.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let choices = labels.clone();
+        Self::Select {
+            get: RowFn::new(move |row| get(row).to_string()),
+            options: labels,
+            set: Rc::new(move |row, label| {
+                let i = choices
+                    .iter()
+                    .position(|choice| *choice == label)
+                    .ok_or_else(|| format!("unknown option {label}"))?;
+                set(row, options[i].clone())
+            }),
+        }
+    }
+}
+
+impl<R> Editor<R> {
+    /// The value of `row` as edited by the editor.
+    pub(crate) fn draft(&self, row: &R) -> String {
+        match self {
+            Self::Text { get, .. } | Self::Select { get, .. } => get.call(row),
+            Self::Number { get, .. } => get.call(row).to_string(),
+            Self::Checkbox { get, .. } => get.call(row).to_string(),
+        }
+    }
+
+    /// Parses and validates `draft`, returning the updated row.
+    pub(crate) fn commit(&self, row: &R, draft: &str) -> Result<R, String> {
+        match self {
+            Self::Text { set, .. } | Self::Select { set, .. } => set(row, draft.to_string()),
+            Self::Number { set, .. } => {
+                let number = draft
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| "not a number".to_string())?;
+                set(row, number)
+            }
+            Self::Checkbox { set, .. } => set(row, draft == "true"),
+        }
+    }
+
+    /// Renders the input for `draft`, `on_change` receives the new draft.
+    pub(crate) fn input(&self, draft: &str, invalid: bool, on_change: Callback<String>) -> Html {
+        match self {
+            Self::Text { .. } | Self::Number { .. } => {
+                let field = match self {
+                    Self::Number { .. } => input().type_number(),
+                    _ => input().type_text(),
+                };
+                field
+                    .value(draft.to_string())
+                    .attr("aria-invalid", invalid.to_string())
+                    .oninput(move |e: InputEvent| {
+                        on_change.emit(e.target_unchecked_into::<HtmlInputElement>().value())
+                    })
+                    .to_vnode()
+            }
+            Self::Checkbox { .. } => checkbox()
+                .checked(draft == "true")
+                .onchange(move |e: Event| {
+                    let checked = e.target_unchecked_into::<HtmlInputElement>().checked();
+                    on_change.emit(checked.to_string())
+                })
+                .to_vnode(),
+            Self::Select { options, .. } => comp_with::<DropDown<String>>(DropDownProps {
+                initial: draft.to_string(),
+                options: options.clone(),
+                selection_changed: on_change,
+            })
+            .to_vnode(),
+        }
+    }
+}
+
+impl<R> PartialEq for Editor<R> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text { get, set }, Self::Text { get: g, set: s }) => {
+                get == g && Rc::ptr_eq(set, s)
+            }
+            (Self::Number { get, set }, Self::Number { get: g, set: s }) => {
+                get == g && Rc::ptr_eq(set, s)
+            }
+            (Self::Checkbox { get, set }, Self::Checkbox { get: g, set: s }) => {
+                get == g && Rc::ptr_eq(set, s)
+            }
+            (
+                Self::Select { get, options, set },
+                Self::Select {
+                    get: g,
+                    options: o,
+                    set: s,
+                },
+            ) => get == g && options == o && Rc::ptr_eq(set, s),
+            _ => false,
+        }
+    }
+}
+
+/// A committed edit of a cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellEdit<R> {
+    /// Key of the edited row.
+    pub key: RowKey,
+    /// Index of the edited row in the rows of the table.
+    pub index: usize,
+    /// Index of the column definition of the edited cell.
+    pub column: usize,
+    /// The row with the new value.
+    pub row: R,
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+use super::Column;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+/// Serializes `rows` in `format`, see [to_csv] and [to_json].
+pub fn export<'a, R: 'a>(
+    format: ExportFormat,
+    columns: &[Column<R>],
+    rows: impl IntoIterator<Item = &'a R>,
+) -> String {
+    match format {
+        ExportFormat::Csv => to_csv(columns, rows),
+        ExportFormat::Json => to_json(columns, rows),
+    }
+}
+
+fn exported_columns<R>(columns: &[Column<R>]) -> impl Iterator<Item = &Column<R>> + Clone {
+    columns.iter().filter(|column| column.has_value())
+}
+
+/// Serializes `rows` as CSV with a header line. Only columns with a
+/// [value](Column::value) are exported.
+///
+/// ```
+/// use yew_utils::components::table::{to_csv, Column};
+/// use yew_utils::vdom::*;
+///
+/// let columns = vec![
+///     Column::new("name", |r: &(&str, u32)| text(r.0).into()).value(|r| r.0.to_string()),
+///     Column::new("qty", |r: &(&str, u32)| text(r.1.to_string()).into()).value(|r| r.1.to_string()),
+/// ];
+/// let rows = [("apple", 3), ("pear, \"green\"", 2)];
+/// assert_eq!(
+///     to_csv(&columns, &rows),
+///     "name,qty\r\napple,3\r\n\"pear, \"\"green\"\"\",2\r\n"
+/// );
+/// ```
+pub fn to_csv<'a, R: 'a>(columns: &[Column<R>], rows: impl IntoIterator<Item = &'a R>) -> String {
+    fn field(value: &str) -> String {
+        if value.contains([',', '"', '\r', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    let columns = exported_columns(columns);
+    let mut csv = String::new();
+    let header = columns.clone().map(|column| field(column.header()));
+    csv.push_str(&header.collect::<Vec<_>>().join(","));
+    csv.push_str("\r\n");
+    for row in rows {
+        let line = columns
+            .clone()
+            .map(|column| field(&column.text(row).unwrap_or_default()));
+        csv.push_str(&line.collect::<Vec<_>>().join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Serializes `rows` as a JSON array of objects, the column headers are the
+/// keys. Repeated headers get a number appended, e.g. `"price (2)"`, to keep
+/// the keys unique. Only columns with a [value](Column::value) are exported.
+///
+/// ```
+/// use yew_utils::components::table::{to_json, Column};
+/// use yew_utils::vdom::*;
+///
+/// let columns = vec![
+///     Column::new("name", |r: &&str| text(*r).into()).value(|r| r.to_string()),
+///     Column::new("icon", |r: &&str| text("*").into()),
+///     Column::new("name", |r: &&str| text(*r).into()).value(|r| r.len().to_string()),
+/// ];
+/// let rows = ["apple", "say \"hi\""];
+/// assert_eq!(
+///     to_json(&columns, &rows),
+///     r#"[{"name":"apple","name (2)":"5"},{"name":"say \"hi\"","name (2)":"8"}]"#
+/// );
+/// ```
+pub fn to_json<'a, R: 'a>(columns: &[Column<R>], rows: impl IntoIterator<Item = &'a R>) -> String {
+    fn string(value: &str) -> String {
+        let mut json = String::with_capacity(value.len() + 2);
+        json.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => json.push_str("\\\""),
+                '\\' => json.push_str("\\\\"),
+                '\n' => json.push_str("\\n"),
+                '\r' => json.push_str("\\r"),
+                '\t' => json.push_str("\\t"),
+                c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+                c => json.push(c),
+            }
+        }
+        json.push('"');
+        json
+    }
+
+    let columns = exported_columns(columns).collect::<Vec<_>>();
+    let mut seen = HashMap::<&str, usize>::new();
+    let keys = columns
+        .iter()
+        .map(|column| {
+            let header = column.header().as_str();
+            let count = seen.entry(header).or_default();
+            *count += 1;
+            match count {
+                1 => string(header),
+                n => string(&format!("{header} ({n})")),
+            }
+        })
+        .collect::<Vec<_>>();
+    let objects = rows.into_iter().map(|row| {
+        let fields = columns.iter().zip(&keys).map(|(column, key)| {
+            format!("{key}:{}", string(&column.text(row).unwrap_or_default()))
+        });
+        format!("{{{}}}", fields.collect::<Vec<_>>().join(","))
+    });
+    format!("[{}]", objects.collect::<Vec<_>>().join(","))
+}
+
+/// Lets the browser download `content` as a file named `filename`.
+///
+/// The object URL of the file is revoked after a second, some browsers start
+/// the download only after `click()` returned.
+pub fn download(filename: &str, mime_type: &str, content: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    let revoke = Closure::once_into_js(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    web_sys::window()
+        .ok_or_else(|| JsValue::from_str("no window"))?
+        .set_timeout_with_callback_and_timeout_and_arguments_0(revoke.unchecked_ref(), 1000)?;
+    Ok(())
+}
@@ -0,0 +1,320 @@
+use std::collections::BTreeMap;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::{Column, RowFn};
+use crate::components::drop_down::{DropDown, DropDownProps};
+use crate::vdom::{button, comp_with, div, input, span};
+
+/// Filter of a [Column]. Each filter type extracts the value it filters on
+/// from a row.
+pub enum Filter<R> {
+    /// Rows whose text contains the filter text (case insensitive).
+    Text(RowFn<R, String>),
+    /// Rows whose number lies within an optional min and max.
+    NumberRange(RowFn<R, f64>),
+    /// Rows whose value is one of the chosen `options`. Nothing chosen matches
+    /// all rows.
+    Select {
+        value: RowFn<R, String>,
+        options: Vec<String>,
+    },
+    /// Rows whose ISO 8601 date (`YYYY-MM-DD`) lies within an optional range.
+    DateRange(RowFn<R, String>),
+}
+
+impl<R> Filter<R> {
+    pub fn text(value: impl Fn(&R) -> String + 'static) -> Self {
+        Self::Text(RowFn::new(value))
+    }
+
+    pub fn number_range(value: impl Fn(&R) -> f64 + 'static) -> Self {
+        Self::NumberRange(RowFn::new(value))
+    }
+
+    pub fn select(
+        value: impl Fn(&R) -> String + 'static,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self::Select {
+            value: RowFn::new(value),
+            options: options.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn date_range(value: impl Fn(&R) -> String + 'static) -> Self {
+        Self::DateRange(RowFn::new(value))
+    }
+
+    pub fn matches(&self, row: &R, filter: &FilterValue) -> bool {
+        fn within<T: PartialOrd>(value: T, min: Option<T>, max: Option<T>) -> bool {
+            min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+        }
+
+        match (self, filter) {
+            (Self::Text(value), FilterValue::Text(text)) => contains(&value.call(row), text),
+            (Self::NumberRange(value), FilterValue::Range { min, max }) => {
+                within(value.call(row), *min, *max)
+            }
+            (Self::Select { value, .. }, FilterValue::Select(chosen)) => {
+                chosen.is_empty() || chosen.contains(&value.call(row))
+            }
+            (Self::DateRange(value), FilterValue::DateRange { from, to }) => {
+                within(value.call(row), from.clone(), to.clone())
+            }
+            // a filter value that doesn't fit the filter type is ignored
+            _ => true,
+        }
+    }
+}
+
+impl<R> PartialEq for Filter<R> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::NumberRange(a), Self::NumberRange(b)) => a == b,
+            (
+                Self::Select { value, options },
+                Self::Select {
+                    value: value2,
+                    options: options2,
+                },
+            ) => value == value2 && options == options2,
+            (Self::DateRange(a), Self::DateRange(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The value of a column filter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterValue {
+    Text(String),
+    Range {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Select(Vec<String>),
+    DateRange {
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+/// The global search text and column filters of a
+/// [DataTable](super::DataTable). Column filters are keyed by the index of the
+/// column definition.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FilterState {
+    pub search: String,
+    pub columns: BTreeMap<usize, FilterValue>,
+}
+
+impl FilterState {
+    pub fn is_empty(&self) -> bool {
+        self.search.is_empty() && self.columns.is_empty()
+    }
+}
+
+fn contains(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// Returns the indexes of the `rows` that pass all column filters and contain
+/// the search text in at least one column [value](Column::value). The search
+/// is ignored if no column has a value.
+///
+/// ```
+/// use yew_utils::components::table::{filtered_indices, Column, Filter, FilterState, FilterValue};
+/// use yew_utils::vdom::*;
+///
+/// type Row = (&'static str, f64);
+///
+/// let columns = vec![
+///     Column::new("name", |r: &Row| text(r.0).into())
+///         .value(|r| r.0.to_string())
+///         .filter(Filter::text(|r: &Row| r.0.to_string())),
+///     Column::new("price", |r: &Row| text(r.1.to_string()).into())
+///         .filter(Filter::number_range(|r: &Row| r.1)),
+/// ];
+/// let rows = vec![("apple", 1.0), ("banana", 2.5), ("cherry", 7.0)];
+///
+/// let mut state = FilterState::default();
+/// state.columns.insert(1, FilterValue::Range { min: Some(2.0), max: None });
+/// assert_eq!(filtered_indices(&rows, &columns, &state), vec![1, 2]);
+///
+/// state.search = "CHE".to_string();
+/// assert_eq!(filtered_indices(&rows, &columns, &state), vec![2]);
+/// ```
+pub fn filtered_indices<R>(rows: &[R], columns: &[Column<R>], state: &FilterState) -> Vec<usize> {
+    let searchable = columns.iter().any(Column::has_value);
+    let search = match searchable {
+        true => state.search.trim(),
+        false => "",
+    };
+    (0..rows.len())
+        .filter(|&i| {
+            let row = &rows[i];
+            let filters_match = state.columns.iter().all(|(&column, value)| {
+                columns
+                    .get(column)
+                    .and_then(|column| column.filter_def())
+                    .is_none_or(|filter| filter.matches(row, value))
+            });
+            let search_matches = search.is_empty()
+                || columns
+                    .iter()
+                    .filter_map(|column| column.text(row))
+                    .any(|value| contains(&value, search));
+            filters_match && search_matches
+        })
+        .collect()
+}
+
+fn input_value(e: InputEvent) -> String {
+    e.target_unchecked_into::<HtmlInputElement>().value()
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// Renders the input(s) for `filter`. `on_change` receives `None` when the
+/// filter is cleared.
+pub(crate) fn filter_input<R>(
+    filter: &Filter<R>,
+    value: Option<&FilterValue>,
+    on_change: Callback<Option<FilterValue>>,
+) -> Html {
+    match filter {
+        Filter::Text(_) => {
+            let text = match value {
+                Some(FilterValue::Text(text)) => text.clone(),
+                _ => String::new(),
+            };
+            input()
+                .type_search()
+                .attr("aria-label", "filter")
+                .value(text)
+                .oninput(move |e| on_change.emit(non_empty(input_value(e)).map(FilterValue::Text)))
+                .to_vnode()
+        }
+
+        Filter::NumberRange(_) => {
+            let (min, max) = match value {
+                Some(FilterValue::Range { min, max }) => (*min, *max),
+                _ => (None, None),
+            };
+            let bound = |label: &'static str, current: Option<f64>, set_min: bool| {
+                let on_change = on_change.clone();
+                // only the initial value is set, so that partial input like
+                // "1." isn't replaced while typing
+                input()
+                    .type_number()
+                    .attr("placeholder", label)
+                    .attr("aria-label", label)
+                    .attr("value", current.map(|n| n.to_string()).unwrap_or_default())
+                    .oninput(move |e| {
+                        let n = input_value(e).parse::<f64>().ok();
+                        let (min, max) = if set_min { (n, max) } else { (min, n) };
+                        on_change.emit(
+                            (min.is_some() || max.is_some())
+                                .then_some(FilterValue::Range { min, max }),
+                        );
+                    })
+            };
+            div()
+                .append_all([bound("min", min, true), bound("max", max, false)])
+                .to_vnode()
+        }
+
+        Filter::DateRange(_) => {
+            let (from, to) = match value {
+                Some(FilterValue::DateRange { from, to }) => (from.clone(), to.clone()),
+                _ => (None, None),
+            };
+            let bound = |label: &'static str, current: Option<String>, set_from: bool| {
+                let on_change = on_change.clone();
+                let (from, to) = (from.clone(), to.clone());
+                input()
+                    .type_date()
+                    .attr("aria-label", label)
+                    .attr("value", current.unwrap_or_default())
+                    .oninput(move |e| {
+                        let date = non_empty(input_value(e));
+                        let (from, to) = if set_from {
+                            (date, to.clone())
+                        } else {
+                            (from.clone(), date)
+                        };
+                        on_change.emit(
+                            (from.is_some() || to.is_some())
+                                .then_some(FilterValue::DateRange { from, to }),
+                        );
+                    })
+            };
+            div()
+                .append_all([
+                    bound("from", from.clone(), true),
+                    bound("to", to.clone(), false),
+                ])
+                .to_vnode()
+        }
+
+        Filter::Select { options, .. } => {
+            let chosen = match value {
+                Some(FilterValue::Select(chosen)) => chosen.clone(),
+                _ => Vec::new(),
+            };
+            let update = {
+                let on_change = on_change.clone();
+                move |chosen: Vec<String>| {
+                    on_change.emit((!chosen.is_empty()).then_some(FilterValue::Select(chosen)))
+                }
+            };
+
+            let chips = chosen
+                .iter()
+                .map(|option| {
+                    let update = update.clone();
+                    let remaining = chosen
+                        .iter()
+                        .filter(|chosen| *chosen != option)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    span()
+                        .class("table-filter-chip")
+                        .text(option.clone())
+                        .append(
+                            button()
+                                .attr("type", "button")
+                                .attr("aria-label", format!("remove {option}"))
+                                .onclick(move |_| update(remaining.clone()))
+                                .text("×"),
+                        )
+                })
+                .collect::<Vec<_>>();
+
+            // the empty option resets the drop down after each choice, the
+            // key makes sure it is recreated
+            let key = format!("add-{}", chosen.len());
+            let choices = std::iter::once(String::new())
+                .chain(options.iter().filter(|o| !chosen.contains(o)).cloned())
+                .collect::<Vec<_>>();
+            let add = comp_with::<DropDown<String>>(DropDownProps {
+                initial: String::new(),
+                options: choices,
+                selection_changed: Callback::from(move |option: String| {
+                    if !option.is_empty() {
+                        let mut chosen = chosen.clone();
+                        chosen.push(option);
+                        update(chosen);
+                    }
+                }),
+            })
+            .key(&key);
+
+            div().append_all(chips).append(add).to_vnode()
+        }
+    }
+}
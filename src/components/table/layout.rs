@@ -0,0 +1,64 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Order, widths and visibility of the columns of a
+/// [DataTable](super::DataTable). Columns are identified by the index of their
+/// definition.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnLayout {
+    /// Display order. Columns that are missing are shown after the ordered
+    /// ones.
+    pub order: Vec<usize>,
+    /// Widths in pixels of resized columns.
+    pub widths: BTreeMap<usize, f64>,
+    pub hidden: BTreeSet<usize>,
+}
+
+impl ColumnLayout {
+    /// All of `count` columns in display order, including hidden ones.
+    pub fn ordered(&self, count: usize) -> Vec<usize> {
+        let mut ordered = Vec::with_capacity(count);
+        for column in self.order.iter().copied().chain(0..count) {
+            if column < count && !ordered.contains(&column) {
+                ordered.push(column);
+            }
+        }
+        ordered
+    }
+
+    /// The visible columns in display order.
+    pub fn visible(&self, count: usize) -> Vec<usize> {
+        let mut visible = self.ordered(count);
+        visible.retain(|column| !self.hidden.contains(column));
+        visible
+    }
+
+    /// Moves `column` to the position of `target`.
+    ///
+    /// ```
+    /// use yew_utils::components::table::ColumnLayout;
+    ///
+    /// let mut layout = ColumnLayout::default();
+    /// layout.move_column(4, 0, 2);
+    /// assert_eq!(layout.ordered(4), vec![1, 2, 0, 3]);
+    /// layout.move_column(4, 3, 1);
+    /// assert_eq!(layout.ordered(4), vec![3, 1, 2, 0]);
+    /// ```
+    pub fn move_column(&mut self, count: usize, column: usize, target: usize) {
+        let mut order = self.ordered(count);
+        let (Some(from), Some(to)) = (
+            order.iter().position(|&c| c == column),
+            order.iter().position(|&c| c == target),
+        ) else {
+            return;
+        };
+        order.remove(from);
+        order.insert(to, column);
+        self.order = order;
+    }
+
+    pub fn toggle_hidden(&mut self, column: usize) {
+        if !self.hidden.remove(&column) {
+            self.hidden.insert(column);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use yew::prelude::*;
+
+use super::{FilterState, SortKey};
+use crate::components::drop_down::{DropDown, DropDownProps};
+use crate::vdom::{button, comp_with, div, span};
+
+/// Pagination options of a [DataTable](super::DataTable).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pagination {
+    /// The page sizes the user can choose from.
+    pub page_sizes: Vec<usize>,
+    /// The initial page size.
+    pub page_size: usize,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page_sizes: vec![10, 25, 50, 100],
+            page_size: 25,
+        }
+    }
+}
+
+/// Emitted in server-side mode whenever the table needs a different page.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PageRequest {
+    /// Zero based page index.
+    pub page: usize,
+    pub page_size: usize,
+    pub sort: Vec<SortKey>,
+    pub filters: FilterState,
+}
+
+/// Server-side paging: the rows passed to the table are the current page only
+/// and sorting and filtering is left to whoever answers the [PageRequest]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerPaging {
+    /// Total number of rows of all pages.
+    pub total: usize,
+    pub on_page_request: Callback<PageRequest>,
+}
+
+/// Number of pages needed to show `total` rows. There is always at least one
+/// (possibly empty) page.
+///
+/// ```
+/// use yew_utils::components::table::page_count;
+///
+/// assert_eq!(page_count(0, 10), 1);
+/// assert_eq!(page_count(10, 10), 1);
+/// assert_eq!(page_count(11, 10), 2);
+/// ```
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// The row range `start..end` shown on `page`.
+///
+/// ```
+/// use yew_utils::components::table::page_range;
+///
+/// assert_eq!(page_range(0, 10, 25), 0..10);
+/// assert_eq!(page_range(2, 10, 25), 20..25);
+/// assert_eq!(page_range(5, 10, 25), 25..25);
+/// ```
+pub fn page_range(page: usize, page_size: usize, total: usize) -> std::ops::Range<usize> {
+    let start = page.saturating_mul(page_size).min(total);
+    let end = start.saturating_add(page_size).min(total);
+    start..end
+}
+
+/// Renders the navigation controls, page size selection and "showing X–Y of
+/// Z" label.
+pub(crate) fn pager(
+    page_sizes: &[usize],
+    page: usize,
+    page_size: usize,
+    total: usize,
+    on_page: Callback<usize>,
+    on_page_size: Callback<usize>,
+) -> Html {
+    let pages = page_count(total, page_size);
+    let range = page_range(page, page_size, total);
+    let label = if range.is_empty() {
+        format!("showing 0 of {total}")
+    } else {
+        format!("showing {}–{} of {total}", range.start + 1, range.end)
+    };
+
+    let nav = |text: &'static str, title: &'static str, target: usize, enabled: bool| {
+        let on_page = on_page.clone();
+        let mut button = button()
+            .attr("type", "button")
+            .attr("title", title)
+            .attr("aria-label", title)
+            .onclick(move |_| on_page.emit(target))
+            .text(text);
+        if !enabled {
+            button = button.attr("disabled", "disabled");
+        }
+        button
+    };
+
+    div()
+        .class("table-pagination")
+        .attr("role", "navigation")
+        .append_all([
+            nav("«", "first page", 0, page > 0),
+            nav("‹", "previous page", page.saturating_sub(1), page > 0),
+        ])
+        .append(span().text(format!(" page {} of {pages} ", page + 1)))
+        .append_all([
+            nav("›", "next page", page + 1, page + 1 < pages),
+            nav("»", "last page", pages - 1, page + 1 < pages),
+        ])
+        .append(comp_with::<DropDown<usize>>(DropDownProps {
+            initial: page_size,
+            options: page_sizes.to_vec(),
+            selection_changed: on_page_size,
+        }))
+        .append(span().class("table-pagination-label").text(label))
+        .to_vnode()
+}
@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+/// Rows are identified by the key of their `tr`, e.g. `tr().key(..)`.
+pub type RowKey = Key;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    None,
+    Single,
+    Multi,
+}
+
+/// The selected rows and the last toggled row that a shift-click range starts
+/// from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Selection {
+    selected: HashSet<RowKey>,
+    anchor: Option<RowKey>,
+}
+
+impl Selection {
+    pub(crate) fn new(selected: Vec<RowKey>) -> Self {
+        Self {
+            selected: selected.into_iter().collect(),
+            anchor: None,
+        }
+    }
+
+    /// The selected keys in the order of `rows`, followed by selected keys
+    /// that are not in `rows` (e.g. filtered out) sorted by key.
+    pub(crate) fn keys(&self, rows: &[RowKey]) -> Vec<RowKey> {
+        let mut keys = rows
+            .iter()
+            .filter(|key| self.is_selected(key))
+            .cloned()
+            .collect::<Vec<_>>();
+        let in_rows = rows.iter().collect::<HashSet<_>>();
+        let mut others = self
+            .selected
+            .iter()
+            .filter(|key| !in_rows.contains(key))
+            .cloned()
+            .collect::<Vec<_>>();
+        others.sort_by_key(|key| key.to_string());
+        keys.extend(others);
+        keys
+    }
+
+    pub(crate) fn is_selected(&self, key: &RowKey) -> bool {
+        self.selected.contains(key)
+    }
+
+    /// Toggles the row with `key`. With `range` all rows between the previously
+    /// toggled row and `key` get the new state of `key`.
+    pub(crate) fn toggle(
+        &mut self,
+        mode: SelectionMode,
+        rows: &[RowKey],
+        key: RowKey,
+        range: bool,
+    ) {
+        let select = !self.is_selected(&key);
+        match mode {
+            SelectionMode::None => return,
+            SelectionMode::Single => {
+                self.selected.clear();
+                if select {
+                    self.selected.insert(key.clone());
+                }
+            }
+            SelectionMode::Multi => {
+                let anchor = self
+                    .anchor
+                    .as_ref()
+                    .and_then(|anchor| rows.iter().position(|row| row == anchor));
+                let target = rows.iter().position(|row| row == &key);
+                let keys = match (range, anchor, target) {
+                    (true, Some(a), Some(b)) => &rows[a.min(b)..=a.max(b)],
+                    _ => std::slice::from_ref(&key),
+                };
+                for key in keys {
+                    self.set(key, select);
+                }
+            }
+        }
+        self.anchor = Some(key);
+    }
+
+    /// Selects all `rows` unless all of them are already selected, in which
+    /// case they are deselected.
+    pub(crate) fn toggle_all(&mut self, rows: &[RowKey]) {
+        let select = !self.all_selected(rows);
+        for key in rows {
+            self.set(key, select);
+        }
+    }
+
+    pub(crate) fn all_selected(&self, rows: &[RowKey]) -> bool {
+        !rows.is_empty() && rows.iter().all(|key| self.is_selected(key))
+    }
+
+    pub(crate) fn some_selected(&self, rows: &[RowKey]) -> bool {
+        rows.iter().any(|key| self.is_selected(key))
+    }
+
+    fn set(&mut self, key: &RowKey, select: bool) {
+        match select {
+            true => self.selected.insert(key.clone()),
+            false => self.selected.remove(key),
+        };
+    }
+}
+
+/// The key of `row` if it can be selected: rows without a key or with
+/// `data-selectable="false"` cannot.
+pub(crate) fn row_key(row: &Html) -> Option<&RowKey> {
+    if let Html::VTag(tag) = row {
+        let attributes = tag.attributes.iter();
+        if attributes
+            .into_iter()
+            .any(|(name, value)| name == "data-selectable" && value == "false")
+        {
+            return None;
+        }
+    }
+    row.key()
+}
+
+/// Inserts `child` as the first child of `parent`, e.g. a cell into a row.
+pub(crate) fn prepend_child(parent: Html, child: Html) -> Html {
+    match parent {
+        Html::VTag(mut parent) => {
+            if let Some(children) = parent.children_mut() {
+                let children = children.to_vlist_mut();
+                children.insert(0, child);
+                children.recheck_fully_keyed();
+            }
+            Html::VTag(parent)
+        }
+        parent => parent,
+    }
+}
@@ -0,0 +1,102 @@
+use super::Column;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Sorts by `column` (the index into the column definitions) in `direction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortKey {
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    pub fn ascending(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    pub fn descending(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// Computes the sort state after a header click on `column`. The column cycles
+/// through ascending, descending and unsorted. With `multi` (shift-click) the
+/// other sort keys are kept, otherwise they are replaced.
+///
+/// ```
+/// use yew_utils::components::table::{toggle_sort, SortKey};
+///
+/// let sort = toggle_sort(&[], 0, false);
+/// assert_eq!(sort, vec![SortKey::ascending(0)]);
+/// let sort = toggle_sort(&sort, 1, true);
+/// assert_eq!(sort, vec![SortKey::ascending(0), SortKey::ascending(1)]);
+/// let sort = toggle_sort(&sort, 0, true);
+/// assert_eq!(sort, vec![SortKey::descending(0), SortKey::ascending(1)]);
+/// let sort = toggle_sort(&sort, 0, true);
+/// assert_eq!(sort, vec![SortKey::ascending(1)]);
+/// let sort = toggle_sort(&sort, 0, false);
+/// assert_eq!(sort, vec![SortKey::ascending(0)]);
+/// ```
+pub fn toggle_sort(sort: &[SortKey], column: usize, multi: bool) -> Vec<SortKey> {
+    let pos = sort.iter().position(|key| key.column == column);
+    let next = match pos.map(|pos| sort[pos].direction) {
+        None => Some(SortDirection::Ascending),
+        Some(SortDirection::Ascending) => Some(SortDirection::Descending),
+        Some(SortDirection::Descending) => None,
+    };
+
+    if !multi {
+        return next
+            .map(|direction| vec![SortKey { column, direction }])
+            .unwrap_or_default();
+    }
+
+    let mut sort = sort.to_vec();
+    match (pos, next) {
+        (Some(pos), Some(direction)) => sort[pos].direction = direction,
+        (Some(pos), None) => {
+            sort.remove(pos);
+        }
+        (None, Some(direction)) => sort.push(SortKey { column, direction }),
+        (None, None) => {}
+    }
+    sort
+}
+
+/// Returns the indexes of `rows` in sorted order. Sorting is stable, rows that
+/// compare equal keep their original order.
+pub fn sorted_indices<R>(rows: &[R], columns: &[Column<R>], sort: &[SortKey]) -> Vec<usize> {
+    let mut indices = (0..rows.len()).collect::<Vec<_>>();
+    sort_indices(&mut indices, rows, columns, sort);
+    indices
+}
+
+/// Sorts `indices` into `rows`, see [sorted_indices].
+pub fn sort_indices<R>(indices: &mut [usize], rows: &[R], columns: &[Column<R>], sort: &[SortKey]) {
+    if sort.is_empty() {
+        return;
+    }
+    indices.sort_by(|&a, &b| {
+        sort.iter()
+            .filter_map(|key| columns.get(key.column).map(|column| (key, column)))
+            .map(|(key, column)| {
+                let ordering = column.compare(&rows[a], &rows[b]);
+                match key.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
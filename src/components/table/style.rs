@@ -0,0 +1,190 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use web_sys::Element;
+use yew::prelude::*;
+use yew::virtual_dom::VTag;
+
+use super::TableProps;
+
+/// Unique id of a table, used to scope its style sheet.
+pub(crate) fn next_id() -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    format!("yew-table-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// The classes of the `table` element: the user provided ones, one per enabled
+/// variant and, with the `mui-css` feature, the mui-css table classes.
+pub(crate) fn table_classes(props: &TableProps) -> Classes {
+    let mut classes = props.class.clone();
+    #[cfg(feature = "mui-css")]
+    {
+        classes.push("mui-table");
+        if props.bordered {
+            classes.push("mui-table--bordered");
+        }
+    }
+    for (enabled, class) in [
+        (props.striped, "table-striped"),
+        (props.bordered, "table-bordered"),
+        (props.compact, "table-compact"),
+        (props.hoverable, "table-hoverable"),
+    ] {
+        if enabled {
+            classes.push(class);
+        }
+    }
+    classes
+}
+
+/// Offsets in pixels of the frozen leading and trailing columns from the
+/// respective edge of the table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrozenOffsets {
+    pub(crate) start: Vec<f64>,
+    pub(crate) end: Vec<f64>,
+}
+
+impl FrozenOffsets {
+    /// Measures the first `start` and last `end` cells of `row`.
+    pub(crate) fn measure(row: &Element, start: usize, end: usize) -> Self {
+        let cells = row.children();
+        let count = cells.length() as usize;
+        let widths = (0..count)
+            .map(|i| {
+                cells
+                    .item(i as u32)
+                    .map(|cell| cell.get_bounding_client_rect().width())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let offsets = |widths: &mut dyn Iterator<Item = &f64>, n: usize| {
+            widths
+                .take(n)
+                .scan(0.0, |offset, width| {
+                    let current = *offset;
+                    *offset += width;
+                    Some(current)
+                })
+                .collect()
+        };
+        Self {
+            start: offsets(&mut widths.iter(), start.min(count)),
+            end: offsets(&mut widths.iter().rev(), end.min(count)),
+        }
+    }
+}
+
+/// Style rules of the enabled variants, scoped to the table with `id`.
+pub(crate) fn stylesheet(id: &str, props: &TableProps, frozen: &FrozenOffsets) -> String {
+    let mut rules = Vec::new();
+    if props.striped {
+        rules.push(format!(
+            "#{id} > tbody > tr.table-row-striped {{ background-color: rgba(0, 0, 0, 0.04); }}"
+        ));
+    }
+    if props.bordered {
+        rules.push(format!("#{id} {{ border-collapse: collapse; }}"));
+        rules.push(format!(
+            "#{id} th, #{id} td {{ border: 1px solid rgba(0, 0, 0, 0.12); }}"
+        ));
+    }
+    if props.compact {
+        rules.push(format!("#{id} th, #{id} td {{ padding: 2px 6px; }}"));
+    }
+    if props.hoverable {
+        rules.push(format!(
+            "#{id} > tbody > tr:hover {{ background-color: rgba(0, 0, 0, 0.08); }}"
+        ));
+    }
+    // `Canvas` is the page background, sticky parts hide what scrolls below
+    if props.sticky_header {
+        rules.push(format!(
+            "#{id} > thead {{ position: sticky; top: 0; z-index: 2; background-color: Canvas; }}"
+        ));
+    }
+    if props.sticky_footer {
+        rules.push(format!(
+            "#{id} > tfoot {{ position: sticky; bottom: 0; z-index: 2; background-color: Canvas; }}"
+        ));
+    }
+    let frozen_cells = frozen
+        .start
+        .iter()
+        .enumerate()
+        .map(|(i, offset)| ("nth-child", "left", i, offset))
+        .chain(
+            frozen
+                .end
+                .iter()
+                .enumerate()
+                .map(|(i, offset)| ("nth-last-child", "right", i, offset)),
+        );
+    // auxiliary rows span columns, their cells don't line up with the columns
+    for (selector, side, i, offset) in frozen_cells {
+        rules.push(format!(
+            "#{id} tr:not([data-auxiliary]) > :{selector}({}) {{ position: sticky; {side}: {offset}px; z-index: 1; background-color: Canvas; }}",
+            i + 1
+        ));
+    }
+    rules.join("\n")
+}
+
+/// Adds `classes` to the class attribute of `tag`.
+pub(crate) fn add_class(tag: &mut VTag, classes: &Classes) {
+    if classes.is_empty() {
+        return;
+    }
+    let mut merged = tag
+        .attributes
+        .iter()
+        .find(|(key, _)| *key == "class")
+        .map(|(_, class)| Classes::from(class.to_string()))
+        .unwrap_or_default();
+    merged.push(classes.clone());
+    tag.add_attribute("class", merged.to_string());
+}
+
+/// Whether `row` is an auxiliary row, e.g. a group header or a row detail,
+/// marked with a `data-auxiliary` attribute.
+pub(crate) fn is_auxiliary(row: &Html) -> bool {
+    match row {
+        Html::VTag(tag) => tag
+            .attributes
+            .iter()
+            .any(|(name, _)| name == "data-auxiliary"),
+        _ => false,
+    }
+}
+
+/// Which of `rows` get the striped background: every second row, not
+/// counting auxiliary rows.
+pub(crate) fn stripes(rows: &[Html]) -> Vec<bool> {
+    let mut data_rows = 0;
+    rows.iter()
+        .map(|row| {
+            if is_auxiliary(row) {
+                return false;
+            }
+            data_rows += 1;
+            data_rows % 2 == 0
+        })
+        .collect()
+}
+
+/// Adds `row_class` to `row` and `cell_class` to all of its cells.
+pub(crate) fn style_row(row: Html, row_class: &Classes, cell_class: &Classes) -> Html {
+    let Html::VTag(mut row) = row else {
+        return row;
+    };
+    add_class(&mut row, row_class);
+    if !cell_class.is_empty() {
+        if let Some(children) = row.children_mut() {
+            let cells = children.to_vlist_mut();
+            for cell in cells.iter_mut() {
+                if let Html::VTag(cell) = cell {
+                    add_class(cell, cell_class);
+                }
+            }
+        }
+    }
+    Html::VTag(row)
+}
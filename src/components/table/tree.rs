@@ -0,0 +1,17 @@
+/// The child rows of a row of a [DataTable](super::DataTable).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum RowChildren<R> {
+    /// A leaf row without children.
+    #[default]
+    None,
+    Loaded(Vec<R>),
+    /// Children that are not loaded yet. Expanding the row asks for them via
+    /// `on_load_children`.
+    Lazy,
+}
+
+impl<R> RowChildren<R> {
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
@@ -0,0 +1,31 @@
+//! Tabs styled with [mui-css tabs](https://www.muicss.com/docs/v1/css-js/tabs),
+//! see [Tabs].
+
+use yew::prelude::*;
+
+use crate::vdom::{a, div, li, ul, Tag};
+
+pub use super::tabs_shared::{Msg, Tab, Tabs, TabsProps};
+
+pub(super) fn tab_list() -> Tag {
+    ul().class("mui-tabs__bar")
+}
+
+pub(super) fn tab(label: &AttrValue, _active: bool) -> Tag {
+    // no `data-mui-toggle`, the mui-css JS would switch the panes itself
+    a().style("cursor: pointer;").text(label.clone())
+}
+
+pub(super) fn wrap_tab(tab: Tag, active: bool) -> Html {
+    li().attr("role", "presentation")
+        .classes(classes!(active.then_some("mui--is-active")))
+        .append(tab)
+        .to_vnode()
+}
+
+pub(super) fn panel(active: bool) -> Tag {
+    div().classes(classes!(
+        "mui-tabs__pane",
+        active.then_some("mui--is-active")
+    ))
+}
@@ -0,0 +1,26 @@
+//! Tabs with plain markup, see [Tabs].
+
+use yew::prelude::*;
+
+use crate::vdom::{button, div, Tag};
+
+pub use super::tabs_shared::{Msg, Tab, Tabs, TabsProps};
+
+pub(super) fn tab_list() -> Tag {
+    div().class("tabs-list")
+}
+
+pub(super) fn tab(label: &AttrValue, active: bool) -> Tag {
+    button()
+        .attr("type", "button")
+        .classes(classes!("tab", active.then_some("tab--active")))
+        .text(label.clone())
+}
+
+pub(super) fn wrap_tab(tab: Tag, _active: bool) -> Html {
+    tab.to_vnode()
+}
+
+pub(super) fn panel(_active: bool) -> Tag {
+    div().class("tab-panel")
+}
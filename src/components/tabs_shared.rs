@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use super::tabs::{panel, tab, tab_list, wrap_tab};
+use crate::vdom::div;
+
+/// A tab with its label and panel content.
+#[derive(Clone, PartialEq)]
+pub struct Tab {
+    pub label: AttrValue,
+    pub content: Html,
+    pub disabled: bool,
+}
+
+impl Tab {
+    pub fn new(label: impl Into<AttrValue>, content: impl Into<Html>) -> Self {
+        Self {
+            label: label.into(),
+            content: content.into(),
+            disabled: false,
+        }
+    }
+
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Tab list with panels. Only the panel of the selected tab is rendered,
+/// with `keep_mounted` panels stay mounted (but hidden) once they were shown.
+///
+/// The selected tab is either controlled by `selected` or kept by the
+/// component, starting with `initial`. Arrow keys, Home and End move between
+/// the enabled tabs.
+///
+/// ```no_run
+/// use yew_utils::components::tabs::{Tab, Tabs, TabsProps};
+/// use yew_utils::vdom::*;
+/// use yew::prelude::*;
+///
+/// # #[function_component(Example)]
+/// # fn example() -> Html {
+/// comp_with::<Tabs>(TabsProps {
+///     tabs: vec![
+///         Tab::new("General", p().text("general settings")),
+///         Tab::new("Advanced", p().text("advanced settings")),
+///         Tab::new("Experimental", p().text("nothing yet")).disabled(true),
+///     ],
+///     ..Default::default()
+/// })
+/// # .into()
+/// # }
+/// ```
+pub struct Tabs {
+    id: String,
+    selected: usize,
+    visited: HashSet<usize>,
+    /// Tab to focus after rendering, after keyboard navigation.
+    focus: Option<usize>,
+}
+
+pub enum Msg {
+    Selected(usize),
+    Navigated(usize),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TabsProps {
+    pub tabs: Vec<Tab>,
+    /// The selected tab in controlled mode.
+    #[prop_or_default]
+    pub selected: Option<usize>,
+    /// The initially selected tab in uncontrolled mode.
+    #[prop_or_default]
+    pub initial: usize,
+    #[prop_or_default]
+    pub on_change: Callback<usize>,
+    #[prop_or_default]
+    pub keep_mounted: bool,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for TabsProps {
+    fn default() -> Self {
+        Self {
+            tabs: Vec::new(),
+            selected: None,
+            initial: 0,
+            on_change: Callback::noop(),
+            keep_mounted: false,
+            class: Classes::new(),
+        }
+    }
+}
+
+/// The tab that `key` moves to from `current`, skipping disabled tabs.
+fn navigate(disabled: &[bool], current: usize, key: &str) -> Option<usize> {
+    let count = disabled.len();
+    let enabled = |i: &usize| !disabled[*i];
+    match key {
+        "ArrowRight" => (1..=count)
+            .map(|step| (current + step) % count)
+            .find(enabled),
+        "ArrowLeft" => (1..=count)
+            .map(|step| (current + count - step) % count)
+            .find(enabled),
+        "Home" => (0..count).find(enabled),
+        "End" => (0..count).rev().find(enabled),
+        _ => None,
+    }
+}
+
+impl Tabs {
+    fn next_id() -> String {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        format!("yew-tabs-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn current(&self, props: &TabsProps) -> usize {
+        props.selected.unwrap_or(self.selected)
+    }
+
+    fn tab_id(&self, i: usize) -> String {
+        format!("{}-tab-{i}", self.id)
+    }
+
+    fn panel_id(&self, i: usize) -> String {
+        format!("{}-panel-{i}", self.id)
+    }
+
+    fn select(&mut self, ctx: &Context<Self>, i: usize) {
+        if ctx.props().selected.is_none() {
+            self.selected = i;
+            self.visited.insert(i);
+        }
+        ctx.props().on_change.emit(i);
+    }
+}
+
+impl Component for Tabs {
+    type Message = Msg;
+    type Properties = TabsProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let props = ctx.props();
+        let selected = props.initial;
+        Self {
+            id: Self::next_id(),
+            selected,
+            visited: HashSet::from([props.selected.unwrap_or(selected)]),
+            focus: None,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+        let current = self.current(props);
+
+        let tabs = props.tabs.iter().enumerate().map(|(i, t)| {
+            let active = i == current;
+            let mut element = tab(&t.label, active)
+                .id(self.tab_id(i))
+                .attr("role", "tab")
+                .attr("aria-selected", active.to_string())
+                .attr("aria-controls", self.panel_id(i))
+                .attr("tabindex", if active { "0" } else { "-1" });
+            element = match t.disabled {
+                true => element.attr("aria-disabled", "true"),
+                false => element.onclick(link.callback(move |_| Msg::Selected(i))),
+            };
+            wrap_tab(element, active)
+        });
+
+        let disabled = props.tabs.iter().map(|t| t.disabled).collect::<Vec<_>>();
+        let list = tab_list()
+            .attr("role", "tablist")
+            .onkeydown(link.batch_callback(move |e: KeyboardEvent| {
+                let next = navigate(&disabled, current, &e.key())?;
+                e.prevent_default();
+                Some(Msg::Navigated(next))
+            }))
+            .append_all(tabs);
+
+        let panels = props.tabs.iter().enumerate().map(|(i, t)| {
+            let active = i == current;
+            let mounted = active || (props.keep_mounted && self.visited.contains(&i));
+            let mut element = panel(active)
+                .key(i)
+                .id(self.panel_id(i))
+                .attr("role", "tabpanel")
+                .attr("aria-labelledby", self.tab_id(i))
+                .attr("tabindex", "0");
+            if !active {
+                element = element.attr("hidden", "hidden");
+            }
+            element.append_all(mounted.then(|| t.content.clone()))
+        });
+
+        div()
+            .classes(classes!("tabs", props.class.clone()))
+            .append(list)
+            .append_all(panels)
+            .to_vnode()
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Selected(i) => self.select(ctx, i),
+            Msg::Navigated(i) => {
+                self.select(ctx, i);
+                self.focus = Some(i);
+            }
+        }
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        if let Some(selected) = ctx.props().selected {
+            self.visited.insert(selected);
+        }
+        true
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        let Some(i) = self.focus.take() else {
+            return;
+        };
+        let id = self.tab_id(i);
+        let tab = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&id));
+        if let Some(tab) = tab.and_then(|tab| tab.dyn_into::<HtmlElement>().ok()) {
+            let _ = tab.focus();
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! App-wide notifications.
+//!
+//! A [ToastProvider] near the root of the app renders the toasts into a portal
+//! on the document body, components below it push toasts with the [Toaster]
+//! from [use_toaster]. Toasts dismiss themselves after their duration, the
+//! timer is paused while the mouse is over a toast.
+//!
+//! ```no_run
+//! use yew_utils::components::toast::{use_toaster, Toast, ToastProvider, ToastProviderProps};
+//! use yew_utils::vdom::*;
+//! use yew::prelude::*;
+//!
+//! #[function_component(SaveButton)]
+//! fn save_button() -> Html {
+//!     let toaster = use_toaster();
+//!     button()
+//!         .text("Save")
+//!         .onclick(move |_| {
+//!             toaster.push(Toast::success("Saved"));
+//!         })
+//!         .into()
+//! }
+//!
+//! # #[function_component(Example)]
+//! # fn example() -> Html {
+//! comp_with::<ToastProvider>(ToastProviderProps {
+//!     children: Children::new(vec![comp::<SaveButton>().into()]),
+//!     ..Default::default()
+//! })
+//! # .into()
+//! # }
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::vdom::{button, div, portal_to_body, span};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Severity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastAction {
+    pub label: AttrValue,
+    pub on_click: Callback<()>,
+}
+
+/// A notification. Toasts are dismissed after `duration` milliseconds, or
+/// only by the user if it is `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    pub message: AttrValue,
+    pub severity: Severity,
+    pub duration: Option<u32>,
+    /// Buttons next to the message, clicking one also dismisses the toast.
+    pub actions: Vec<ToastAction>,
+}
+
+impl Toast {
+    pub fn new(severity: Severity, message: impl Into<AttrValue>) -> Self {
+        Self {
+            message: message.into(),
+            severity,
+            duration: Some(5000),
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn info(message: impl Into<AttrValue>) -> Self {
+        Self::new(Severity::Info, message)
+    }
+
+    pub fn success(message: impl Into<AttrValue>) -> Self {
+        Self::new(Severity::Success, message)
+    }
+
+    pub fn warning(message: impl Into<AttrValue>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn error(message: impl Into<AttrValue>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    #[must_use]
+    pub fn duration(mut self, milliseconds: u32) -> Self {
+        self.duration = Some(milliseconds);
+        self
+    }
+
+    /// The toast stays until it is dismissed by the user.
+    #[must_use]
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    #[must_use]
+    pub fn action(mut self, label: impl Into<AttrValue>, on_click: Callback<()>) -> Self {
+        self.actions.push(ToastAction {
+            label: label.into(),
+            on_click,
+        });
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// Handle for pushing toasts to the nearest [ToastProvider].
+#[derive(Clone)]
+pub struct Toaster {
+    next_id: Rc<Cell<usize>>,
+    messages: Callback<Msg>,
+}
+
+impl Toaster {
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id.get());
+        self.next_id.set(id.0 + 1);
+        self.messages.emit(Msg::Pushed(id, toast));
+        id
+    }
+
+    pub fn dismiss(&self, id: ToastId) {
+        self.messages.emit(Msg::Dismissed(id));
+    }
+}
+
+impl PartialEq for Toaster {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.next_id, &other.next_id) && self.messages == other.messages
+    }
+}
+
+/// The [Toaster] of the nearest [ToastProvider].
+///
+/// # Panics
+///
+/// When there is no [ToastProvider] above the component.
+#[hook]
+pub fn use_toaster() -> Toaster {
+    use_context::<Toaster>().expect("use_toaster needs a ToastProvider")
+}
+
+/// A pending `setTimeout`, cleared when dropped.
+struct Timeout {
+    handle: i32,
+    _callback: Closure<dyn FnMut()>,
+}
+
+impl Timeout {
+    fn new(milliseconds: f64, f: impl FnOnce() + 'static) -> Option<Self> {
+        let callback = Closure::once(f);
+        let handle = web_sys::window()?
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                callback.as_ref().unchecked_ref(),
+                milliseconds as i32,
+            )
+            .ok()?;
+        Some(Self {
+            handle,
+            _callback: callback,
+        })
+    }
+}
+
+impl Drop for Timeout {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            window.clear_timeout_with_handle(self.handle);
+        }
+    }
+}
+
+struct Entry {
+    id: ToastId,
+    toast: Toast,
+    /// Milliseconds until dismissal, as of `started`.
+    remaining: Option<f64>,
+    started: f64,
+    timer: Option<Timeout>,
+}
+
+pub struct ToastProvider {
+    toaster: Toaster,
+    toasts: Vec<Entry>,
+}
+
+pub enum Msg {
+    Pushed(ToastId, Toast),
+    Dismissed(ToastId),
+    Paused(ToastId),
+    Resumed(ToastId),
+}
+
+#[derive(PartialEq, Properties)]
+pub struct ToastProviderProps {
+    pub children: Children,
+    /// Maximum number of toasts shown at once, the oldest ones are dismissed
+    /// first.
+    #[prop_or(5)]
+    pub max_stack: usize,
+    /// Classes of the toast container.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for ToastProviderProps {
+    fn default() -> Self {
+        Self {
+            children: Children::default(),
+            max_stack: 5,
+            class: Classes::new(),
+        }
+    }
+}
+
+impl ToastProvider {
+    fn start_timer(ctx: &Context<Self>, entry: &mut Entry) {
+        let Some(remaining) = entry.remaining else {
+            return;
+        };
+        let dismiss = ctx.link().callback(Msg::Dismissed);
+        let id = entry.id;
+        entry.started = js_sys::Date::now();
+        entry.timer = Timeout::new(remaining, move || dismiss.emit(id));
+    }
+
+    fn entry(&mut self, id: ToastId) -> Option<&mut Entry> {
+        self.toasts.iter_mut().find(|entry| entry.id == id)
+    }
+
+    fn view_toast(ctx: &Context<Self>, entry: &Entry) -> Html {
+        let link = ctx.link();
+        let id = entry.id;
+        let toast = &entry.toast;
+        let role = match toast.severity {
+            Severity::Warning | Severity::Error => "alert",
+            Severity::Info | Severity::Success => "status",
+        };
+        let mut classes = classes!("toast", format!("toast--{}", toast.severity.name()));
+        if cfg!(feature = "mui-css") {
+            classes.push("mui-panel");
+        }
+
+        let actions = toast.actions.iter().map(|action| {
+            let on_click = action.on_click.clone();
+            let dismiss = link.callback(move |_| Msg::Dismissed(id));
+            button()
+                .attr("type", "button")
+                .class("toast-action")
+                .onclick(move |_| {
+                    on_click.emit(());
+                    dismiss.emit(());
+                })
+                .text(action.label.clone())
+        });
+
+        div()
+            .key(id.0)
+            .classes(classes)
+            .attr("role", role)
+            .onmouseenter(link.callback(move |_| Msg::Paused(id)))
+            .onmouseleave(link.callback(move |_| Msg::Resumed(id)))
+            .append(span().class("toast-message").text(toast.message.clone()))
+            .append_all(actions)
+            .append(
+                button()
+                    .attr("type", "button")
+                    .attr("aria-label", "dismiss")
+                    .class("toast-dismiss")
+                    .onclick(link.callback(move |_| Msg::Dismissed(id)))
+                    .text("×"),
+            )
+            .to_vnode()
+    }
+}
+
+impl Component for ToastProvider {
+    type Message = Msg;
+    type Properties = ToastProviderProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            toaster: Toaster {
+                next_id: Rc::default(),
+                messages: ctx.link().callback(|msg| msg),
+            },
+            toasts: Vec::new(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let stack = div()
+            .classes(classes!("toasts", props.class.clone()))
+            .style(
+                "position: fixed; bottom: 1em; right: 1em; z-index: 1000; \
+                 display: flex; flex-direction: column; gap: 0.5em;",
+            )
+            .append_all(self.toasts.iter().map(|entry| Self::view_toast(ctx, entry)));
+
+        html! {
+            <ContextProvider<Toaster> context={self.toaster.clone()}>
+                { for props.children.iter() }
+                { portal_to_body().append(stack) }
+            </ContextProvider<Toaster>>
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Pushed(id, toast) => {
+                let mut entry = Entry {
+                    id,
+                    remaining: toast.duration.map(f64::from),
+                    toast,
+                    started: 0.0,
+                    timer: None,
+                };
+                Self::start_timer(ctx, &mut entry);
+                self.toasts.push(entry);
+                let excess = self.toasts.len().saturating_sub(ctx.props().max_stack);
+                self.toasts.drain(..excess);
+                true
+            }
+            Msg::Dismissed(id) => {
+                let count = self.toasts.len();
+                self.toasts.retain(|entry| entry.id != id);
+                self.toasts.len() != count
+            }
+            Msg::Paused(id) => {
+                if let Some(entry) = self.entry(id) {
+                    if entry.timer.take().is_some() {
+                        let elapsed = js_sys::Date::now() - entry.started;
+                        entry.remaining = entry.remaining.map(|ms| (ms - elapsed).max(0.0));
+                    }
+                }
+                false
+            }
+            Msg::Resumed(id) => {
+                if let Some(entry) = self.entry(id) {
+                    if entry.timer.is_none() {
+                        Self::start_timer(ctx, entry);
+                    }
+                }
+                false
+            }
+        }
+    }
+}
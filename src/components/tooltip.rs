@@ -0,0 +1,146 @@
+//! Short text shown next to an element while it is hovered or focused.
+//!
+//! ```no_run
+//! use yew_utils::components::tooltip::{Tooltip, TooltipProps};
+//! use yew_utils::vdom::*;
+//! use yew::prelude::*;
+//!
+//! # #[function_component(Example)]
+//! # fn example() -> Html {
+//! comp_with::<Tooltip>(TooltipProps {
+//!     text: "Removes the file".into(),
+//!     children: Children::new(vec![button().text("Delete").into()]),
+//!     ..Default::default()
+//! })
+//! # .into()
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use yew::prelude::*;
+
+use super::placement::{Align, Placement, Side};
+use super::popover::{Popover, PopoverProps};
+use crate::vdom::{comp_with, span};
+
+pub struct Tooltip {
+    id: String,
+    anchor: NodeRef,
+    visible: bool,
+}
+
+pub enum Msg {
+    Shown,
+    Hidden,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TooltipProps {
+    pub text: AttrValue,
+    /// The element the tooltip describes. Element children get an
+    /// `aria-describedby` that refers to the text of the tooltip, so they
+    /// should be focusable themselves, e.g. buttons or links.
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or(Placement::new(Side::Top, Align::Center))]
+    pub placement: Placement,
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+impl Default for TooltipProps {
+    fn default() -> Self {
+        Self {
+            text: AttrValue::default(),
+            children: Children::default(),
+            placement: Placement::new(Side::Top, Align::Center),
+            class: Classes::new(),
+        }
+    }
+}
+
+impl Tooltip {
+    fn next_id() -> String {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        format!("yew-tooltip-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Adds the id of the tooltip text to the `aria-describedby` of `child`.
+    fn described(&self, child: Html) -> Html {
+        let Html::VTag(mut tag) = child else {
+            return child;
+        };
+        let ids = match tag
+            .attributes
+            .iter()
+            .find(|(key, _)| *key == "aria-describedby")
+        {
+            Some((_, ids)) => format!("{ids} {}", self.id),
+            None => self.id.clone(),
+        };
+        tag.add_attribute("aria-describedby", ids);
+        Html::VTag(tag)
+    }
+}
+
+impl Component for Tooltip {
+    type Message = Msg;
+    type Properties = TooltipProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            id: Self::next_id(),
+            anchor: NodeRef::default(),
+            visible: false,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let link = ctx.link();
+
+        let tooltip = comp_with::<Popover>(PopoverProps {
+            anchor: self.anchor.clone(),
+            open: self.visible,
+            placement: props.placement,
+            offset: 4.0,
+            children: Children::new(vec![span()
+                .attr("role", "tooltip")
+                .text(props.text.clone())
+                .into()]),
+            class: classes!("tooltip", props.class.clone()),
+        });
+
+        span()
+            .node_ref(self.anchor.clone())
+            .class("tooltip-anchor")
+            .style("display: inline-block;")
+            .onmouseenter(link.callback(|_| Msg::Shown))
+            .onmouseleave(link.callback(|_| Msg::Hidden))
+            .onfocusin(link.callback(|_| Msg::Shown))
+            .onfocusout(link.callback(|_| Msg::Hidden))
+            .onkeydown(
+                link.batch_callback(|e: KeyboardEvent| {
+                    (e.key() == "Escape").then_some(Msg::Hidden)
+                }),
+            )
+            .append_all(props.children.iter().map(|child| self.described(child)))
+            // the popover is only rendered while open, descriptions may refer
+            // to hidden elements
+            .append(
+                span()
+                    .id(self.id.clone())
+                    .attr("hidden", "")
+                    .text(props.text.clone()),
+            )
+            .append(tooltip)
+            .to_vnode()
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let visible = matches!(msg, Msg::Shown);
+        let changed = visible != self.visible;
+        self.visible = visible;
+        changed
+    }
+}
@@ -0,0 +1,297 @@
+//! Renders only the visible part of long lists.
+//!
+//! ```no_run
+//! use yew_utils::components::virtual_list::{RowHeight, VirtualList, VirtualListProps};
+//! use yew_utils::vdom::*;
+//! use yew::prelude::*;
+//!
+//! # #[function_component(Example)]
+//! # fn example() -> Html {
+//! let items = (0..50_000)
+//!     .map(|i| div().key(i).text(format!("item {i}")).to_vnode())
+//!     .collect::<Vec<_>>();
+//!
+//! comp_with::<VirtualList>(VirtualListProps {
+//!     children: Children::new(items),
+//!     height: 400.0,
+//!     row_height: RowHeight::Fixed(24.0),
+//!     overscan: 5,
+//! })
+//! # .into()
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::ops::Range;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::virtual_dom::{Key, VNode};
+
+use crate::vdom::div;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All rows have the same height in pixels.
+    Fixed(f64),
+    /// Rows are measured after they are rendered, `estimate` is used for rows
+    /// that have not been rendered yet.
+    Measured { estimate: f64 },
+}
+
+/// The rows to render and the space to reserve before and after them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Window {
+    pub range: Range<usize>,
+    pub before: f64,
+    pub after: f64,
+}
+
+/// Computes which of `count` rows are visible in a viewport of `height`
+/// pixels scrolled to `scroll_top`, extended by `overscan` rows on each side.
+///
+/// ```
+/// use yew_utils::components::virtual_list::window;
+///
+/// let w = window(100, |_| 10.0, 95.0, 50.0, 2);
+/// assert_eq!(w.range, 7..17);
+/// assert_eq!(w.before, 70.0);
+/// assert_eq!(w.after, 830.0);
+/// ```
+pub fn window(
+    count: usize,
+    row_height: impl Fn(usize) -> f64,
+    scroll_top: f64,
+    height: f64,
+    overscan: usize,
+) -> Window {
+    let mut offsets = Vec::with_capacity(count + 1);
+    let mut offset = 0.0;
+    offsets.push(offset);
+    for i in 0..count {
+        offset += row_height(i);
+        offsets.push(offset);
+    }
+
+    // first row whose bottom is below scroll_top, first row whose top is
+    // below the viewport
+    let first = offsets[1..].partition_point(|&bottom| bottom <= scroll_top);
+    let last = offsets[..count].partition_point(|&top| top < scroll_top + height);
+
+    let start = first.saturating_sub(overscan);
+    let end = (last + overscan).min(count).max(start);
+    Window {
+        range: start..end,
+        before: offsets[start],
+        after: offsets[count] - offsets[end],
+    }
+}
+
+/// Virtualization state shared by [VirtualList] and the virtual scrolling mode
+/// of [Table](super::table::Table).
+#[derive(Default)]
+pub(crate) struct Virtualizer {
+    scroll_top: f64,
+    measured: HashMap<Key, f64>,
+    restore_scroll: Option<f64>,
+}
+
+impl Virtualizer {
+    fn row_key(children: &[VNode], index: usize) -> Key {
+        children[index]
+            .key()
+            .cloned()
+            .unwrap_or_else(|| index.into())
+    }
+
+    fn height_of(&self, children: &[VNode], row_height: RowHeight, index: usize) -> f64 {
+        match row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured { estimate } => self
+                .measured
+                .get(&Self::row_key(children, index))
+                .copied()
+                .unwrap_or(estimate),
+        }
+    }
+
+    pub(crate) fn window(
+        &self,
+        children: &[VNode],
+        row_height: RowHeight,
+        height: f64,
+        overscan: usize,
+    ) -> Window {
+        window(
+            children.len(),
+            |i| self.height_of(children, row_height, i),
+            self.scroll_top,
+            height,
+            overscan,
+        )
+    }
+
+    pub(crate) fn scrolled(&mut self, container: &NodeRef) {
+        if let Some(container) = container.cast::<Element>() {
+            self.scroll_top = container.scroll_top() as f64;
+        }
+    }
+
+    /// Records the heights of `rows`, the rendered elements of the children
+    /// in `range`. Returns true if a height changed.
+    pub(crate) fn measure(
+        &mut self,
+        children: &[VNode],
+        range: Range<usize>,
+        rows: impl Iterator<Item = Element>,
+    ) -> bool {
+        let mut changed = false;
+        for (index, row) in range.zip(rows) {
+            let height = row.get_bounding_client_rect().height();
+            let previous = self.measured.insert(Self::row_key(children, index), height);
+            changed |= previous.is_none_or(|previous| (previous - height).abs() > 0.5);
+        }
+        changed
+    }
+
+    /// Call when the rows change. Keeps the first visible row at the same
+    /// position, if it still exists.
+    pub(crate) fn rows_changed(&mut self, old: &[VNode], new: &[VNode], row_height: RowHeight) {
+        let offset_of = |children: &[VNode], index: usize| {
+            (0..index)
+                .map(|i| self.height_of(children, row_height, i))
+                .sum::<f64>()
+        };
+        let first = window(
+            old.len(),
+            |i| self.height_of(old, row_height, i),
+            self.scroll_top,
+            0.0,
+            0,
+        )
+        .range
+        .start;
+        let Some(anchor) = old.get(first).and_then(|row| row.key()) else {
+            return;
+        };
+        let Some(new_index) = new.iter().position(|row| row.key() == Some(anchor)) else {
+            return;
+        };
+
+        let delta = self.scroll_top - offset_of(old, first);
+        let scroll_top = offset_of(new, new_index) + delta;
+        if (scroll_top - self.scroll_top).abs() > 0.5 {
+            self.scroll_top = scroll_top;
+            self.restore_scroll = Some(scroll_top);
+        }
+    }
+
+    /// Applies a scroll position computed in [Self::rows_changed].
+    pub(crate) fn restore_scroll(&mut self, container: &NodeRef) {
+        if let (Some(scroll_top), Some(container)) =
+            (self.restore_scroll.take(), container.cast::<Element>())
+        {
+            container.set_scroll_top(scroll_top as i32);
+        }
+    }
+}
+
+/// A scroll container that only renders the children in view plus
+/// `overscan`. Each child is expected to render as exactly one element and
+/// should be keyed so that the scroll position stays stable when the
+/// children change.
+pub struct VirtualList {
+    virtualizer: Virtualizer,
+    container: NodeRef,
+    content: NodeRef,
+}
+
+pub enum Msg {
+    Scrolled,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct VirtualListProps {
+    pub children: Children,
+    /// Height of the scroll container in pixels.
+    pub height: f64,
+    pub row_height: RowHeight,
+    #[prop_or(5)]
+    pub overscan: usize,
+}
+
+impl Component for VirtualList {
+    type Message = Msg;
+    type Properties = VirtualListProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            virtualizer: Virtualizer::default(),
+            container: NodeRef::default(),
+            content: NodeRef::default(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let children = props.children.iter().collect::<Vec<_>>();
+        let window =
+            self.virtualizer
+                .window(&children, props.row_height, props.height, props.overscan);
+
+        div()
+            .node_ref(self.container.clone())
+            .style(format!(
+                "overflow-y: auto; position: relative; height: {}px;",
+                props.height
+            ))
+            .onscroll(ctx.link().callback(|_| Msg::Scrolled))
+            .append(
+                div()
+                    .node_ref(self.content.clone())
+                    .style(format!(
+                        "padding-top: {}px; padding-bottom: {}px;",
+                        window.before, window.after
+                    ))
+                    .append_all(children[window.range].iter().cloned()),
+            )
+            .to_vnode()
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Scrolled => {
+                self.virtualizer.scrolled(&self.container);
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let old = old_props.children.iter().collect::<Vec<_>>();
+        let new = ctx.props().children.iter().collect::<Vec<_>>();
+        self.virtualizer
+            .rows_changed(&old, &new, ctx.props().row_height);
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        self.virtualizer.restore_scroll(&self.container);
+
+        let props = ctx.props();
+        if !matches!(props.row_height, RowHeight::Measured { .. }) {
+            return;
+        }
+        let Some(content) = self.content.cast::<Element>() else {
+            return;
+        };
+        let children = props.children.iter().collect::<Vec<_>>();
+        let window =
+            self.virtualizer
+                .window(&children, props.row_height, props.height, props.overscan);
+        let rows = content.children();
+        let rows = (0..rows.length()).filter_map(|i| rows.item(i));
+        if self.virtualizer.measure(&children, window.range, rows) {
+            ctx.link().send_message(Msg::Scrolled);
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! A calendar date without time zone, as used by `<input type="date">`.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A date in the proleptic Gregorian calendar. Dates are ordered
+/// chronologically and formatted as ISO 8601 (`YYYY-MM-DD`), the format of the
+/// value of date inputs.
+///
+/// ```
+/// use yew_utils::date::Date;
+///
+/// let date: Date = "2024-02-29".parse().unwrap();
+/// assert_eq!(date, Date::new(2024, 2, 29).unwrap());
+/// assert_eq!(date.to_string(), "2024-02-29");
+/// assert!("2023-02-29".parse::<Date>().is_err());
+/// assert!(Date::new(2024, 1, 31) < Date::new(2024, 2, 1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The date, or `None` if the month or day is out of range.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let valid = (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month);
+        valid.then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The current date in the local time zone of the browser.
+    pub fn today() -> Self {
+        let now = js_sys::Date::new_0();
+        Self {
+            year: now.get_full_year() as i32,
+            month: now.get_month() as u8 + 1,
+            day: now.get_date() as u8,
+        }
+    }
+
+    /// ```
+    /// use yew_utils::date::{Date, Weekday};
+    ///
+    /// assert_eq!(Date::new(1970, 1, 1).unwrap().weekday(), Weekday::Thursday);
+    /// assert_eq!(Date::new(2024, 2, 29).unwrap().weekday(), Weekday::Thursday);
+    /// assert_eq!(Date::new(2000, 1, 2).unwrap().weekday(), Weekday::Sunday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days_since_epoch() + 3).rem_euclid(7) as usize)
+    }
+
+    /// The date `days` later, or earlier if negative.
+    ///
+    /// ```
+    /// use yew_utils::date::Date;
+    ///
+    /// let date = Date::new(2024, 2, 28).unwrap();
+    /// assert_eq!(date.add_days(2), Date::new(2024, 3, 1).unwrap());
+    /// assert_eq!(date.add_days(-59), Date::new(2023, 12, 31).unwrap());
+    /// ```
+    #[must_use]
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// The date `months` later, or earlier if negative. The day is clamped to
+    /// the length of the month.
+    ///
+    /// ```
+    /// use yew_utils::date::Date;
+    ///
+    /// let date = Date::new(2024, 1, 31).unwrap();
+    /// assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+    /// assert_eq!(date.add_months(-13), Date::new(2022, 12, 31).unwrap());
+    /// assert_eq!(date.add_months(12 * 3), Date::new(2027, 1, 31).unwrap());
+    /// ```
+    #[must_use]
+    pub fn add_months(self, months: i32) -> Self {
+        let months = self.year * 12 + (self.month as i32 - 1) + months;
+        let year = months.div_euclid(12);
+        let month = months.rem_euclid(12) as u8 + 1;
+        let day = self.day.min(days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// The first day of the month of this date.
+    #[must_use]
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// The number of days from 1970-01-01 to this date, in the proleptic
+    /// Gregorian calendar.
+    ///
+    /// ```
+    /// use yew_utils::date::Date;
+    ///
+    /// assert_eq!(Date::new(1970, 1, 1).unwrap().days_since_epoch(), 0);
+    /// assert_eq!(Date::new(2000, 3, 1).unwrap().days_since_epoch(), 11_017);
+    /// assert_eq!(Date::new(1969, 12, 31).unwrap().days_since_epoch(), -1);
+    /// assert_eq!(Date::new(0, 1, 1).unwrap().days_since_epoch(), -719_528);
+    ///
+    /// // round trips across leap days, centuries and negative years
+    /// for (year, month, day) in [
+    ///     (2024, 2, 29), (2023, 3, 1), (1900, 2, 28), (1900, 3, 1), (2000, 2, 29),
+    ///     (2100, 3, 1), (1600, 2, 29), (1, 1, 1), (0, 2, 29), (-1, 12, 31), (-400, 2, 29),
+    ///     (-401, 3, 1), (9999, 12, 31),
+    /// ] {
+    ///     let date = Date::new(year, month, day).unwrap();
+    ///     assert_eq!(Date::from_days_since_epoch(date.days_since_epoch()), date);
+    /// }
+    /// for days in (-800_000..800_000).step_by(997) {
+    ///     let date = Date::from_days_since_epoch(days);
+    ///     assert_eq!(date.days_since_epoch(), days);
+    ///     assert_eq!(date.add_days(1), Date::from_days_since_epoch(days + 1));
+    /// }
+    /// ```
+    pub fn days_since_epoch(&self) -> i64 {
+        // days_from_civil from http://howardhinnant.github.io/date_algorithms.html
+        let month = self.month as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// The date `days` after 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// The weekday by its index from Monday (0) to Sunday (6), wrapping around.
+    pub fn from_index(index: usize) -> Self {
+        match index % 7 {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            _ => Self::Sunday,
+        }
+    }
+
+    /// The index from Monday (0) to Sunday (6).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The number of days from `start` to this weekday, going forward.
+    ///
+    /// ```
+    /// use yew_utils::date::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.days_since(Weekday::Sunday), 1);
+    /// assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+    /// ```
+    pub fn days_since(self, start: Weekday) -> usize {
+        (self.index() + 7 - start.index()) % 7
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The number of days of `month` (1 to 12) in `year`.
+///
+/// ```
+/// use yew_utils::date::days_in_month;
+///
+/// assert_eq!(days_in_month(2023, 2), 28);
+/// assert_eq!(days_in_month(2024, 2), 29);
+/// assert_eq!(days_in_month(2024, 4), 30);
+/// ```
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("not a date (YYYY-MM-DD): {s}");
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let (year, month, day) = (next()?, next()?, next()?);
+        if year.len() < 4 || month.len() != 2 || day.len() != 2 {
+            return Err(invalid());
+        }
+        let year = year.parse().map_err(|_| invalid())?;
+        let month = month.parse().map_err(|_| invalid())?;
+        let day = day.parse().map_err(|_| invalid())?;
+        Self::new(year, month, day).ok_or_else(invalid)
+    }
+}
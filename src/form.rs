@@ -0,0 +1,626 @@
+//! Form state with validation.
+//!
+//! [use_form] keeps the values of a form in a `T` together with the touched,
+//! dirty and error state of its [Field]s and the state of the submission.
+//! Inputs are bound to fields with [Tag::bind](crate::vdom::Tag::bind).
+//!
+//! Sync validators run whenever a field changes, an async validator runs after
+//! them if they passed. Submitting runs the async validators of values they
+//! haven't checked yet, e.g. initial values, and waits for them. Errors of a
+//! field are shown once it was touched (lost the focus) or the form was
+//! submitted.
+//!
+//! ```no_run
+//! use yew::prelude::*;
+//! use yew_utils::form::{use_form, validate, Field, FormSpec};
+//! use yew_utils::vdom::*;
+//!
+//! #[derive(Clone, Default, PartialEq)]
+//! struct Signup {
+//!     email: String,
+//!     name: String,
+//! }
+//!
+//! async fn email_taken(email: &str) -> bool {
+//!     email == "taken@example.com"
+//! }
+//!
+//! #[function_component(SignupForm)]
+//! fn signup_form() -> Html {
+//!     let signup = use_form(|| {
+//!         FormSpec::new(Signup::default())
+//!             .field(
+//!                 Field::new("email", |s: &Signup| s.email.clone(), |s, v| s.email = v)
+//!                     .validate(validate::required())
+//!                     .validate(validate::email())
+//!                     .validate_async(|email| async move {
+//!                         match email_taken(&email).await {
+//!                             true => Err("already registered".to_string()),
+//!                             false => Ok(()),
+//!                         }
+//!                     }),
+//!             )
+//!             .field(
+//!                 Field::new("name", |s: &Signup| s.name.clone(), |s, v| s.name = v)
+//!                     .validate(validate::max_length(50)),
+//!             )
+//!     });
+//!
+//!     let email = signup.field("email");
+//!     let mut submit = button().attr("type", "submit").text("Sign up");
+//!     if signup.is_submitting() {
+//!         submit = submit.attr("disabled", "disabled");
+//!     }
+//!     form()
+//!         .onsubmit(signup.on_submit(|values: Signup| async move {
+//!             // send `values` to the server
+//!             Ok(())
+//!         }))
+//!         .append(label().text("Email").append(input().type_email().bind(email.clone())))
+//!         .append_all(email.error.map(|error| span().class("error").text(error)))
+//!         .append(label().text("Name").append(input().type_text().bind(signup.field("name"))))
+//!         .append(submit)
+//!         .into()
+//! }
+//! ```
+
+pub mod validate;
+
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use yew::prelude::*;
+
+type Setter<T> = Rc<dyn Fn(&mut T, String)>;
+type SyncValidator<T> = Rc<dyn Fn(&str, &T) -> Result<(), String>>;
+type AsyncValidator = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+/// A field of a form: its name and how its (string) value is read from and
+/// written to the form values.
+pub struct Field<T> {
+    name: AttrValue,
+    get: Rc<dyn Fn(&T) -> String>,
+    set: Setter<T>,
+    validators: Vec<SyncValidator<T>>,
+    async_validator: Option<AsyncValidator>,
+}
+
+impl<T> Field<T> {
+    pub fn new(
+        name: impl Into<AttrValue>,
+        get: impl Fn(&T) -> String + 'static,
+        set: impl Fn(&mut T, String) + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            get: Rc::new(get),
+            set: Rc::new(set),
+            validators: Vec::new(),
+            async_validator: None,
+        }
+    }
+
+    /// Adds a sync validator, see [validate] for the built-in ones.
+    #[must_use]
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&str, &T) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validators.push(Rc::new(validator));
+        self
+    }
+
+    /// Sets the async validator, e.g. a server side check. It runs when the
+    /// value changed and passed the sync validators, and on submit if it
+    /// hasn't checked the current value yet. Results of outdated values are
+    /// ignored.
+    #[must_use]
+    pub fn validate_async<F, Fut>(mut self, validator: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.async_validator = Some(Rc::new(move |value| Box::pin(validator(value))));
+        self
+    }
+
+    pub fn name(&self) -> &AttrValue {
+        &self.name
+    }
+
+    /// The first error of the sync validators.
+    fn check(&self, values: &T) -> Option<String> {
+        let value = (self.get)(values);
+        self.validators
+            .iter()
+            .find_map(|validator| validator(&value, values).err())
+    }
+}
+
+/// Initial values and fields of a form, see [use_form].
+pub struct FormSpec<T> {
+    initial: T,
+    fields: Vec<Field<T>>,
+}
+
+impl<T> FormSpec<T> {
+    pub fn new(initial: T) -> Self {
+        Self {
+            initial,
+            fields: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn field(mut self, field: Field<T>) -> Self {
+        self.fields.push(field);
+        self
+    }
+}
+
+/// Everything an input needs to show and edit a field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldBinding {
+    pub name: AttrValue,
+    pub value: AttrValue,
+    /// The error to show, i.e. only once the field was touched or the form
+    /// was submitted.
+    pub error: Option<AttrValue>,
+    pub touched: bool,
+    pub dirty: bool,
+    /// An async validation is running.
+    pub validating: bool,
+    /// Sets the value.
+    pub on_input: Callback<String>,
+    /// Marks the field as touched.
+    pub on_blur: Callback<()>,
+}
+
+pub(crate) enum Action {
+    Set {
+        name: AttrValue,
+        value: String,
+    },
+    Touched(AttrValue),
+    /// Ignored if the field doesn't pass its sync validators (anymore).
+    AsyncStarted {
+        name: AttrValue,
+        run: usize,
+    },
+    AsyncFinished {
+        name: AttrValue,
+        run: usize,
+        value: String,
+        result: Result<(), String>,
+    },
+    SubmitAttempted,
+    SubmitStarted,
+    /// The submission was given up because an async validation failed.
+    SubmitCancelled,
+    SubmitFinished(Result<(), String>),
+    Reset,
+}
+
+pub(crate) struct FormState<T> {
+    fields: Rc<Vec<Field<T>>>,
+    initial: T,
+    values: T,
+    touched: HashSet<AttrValue>,
+    errors: HashMap<AttrValue, String>,
+    async_errors: HashMap<AttrValue, String>,
+    /// The value of a field that the async validator last checked.
+    async_checked: HashMap<AttrValue, String>,
+    /// The run of the async validation of a field that is in progress.
+    validating: HashMap<AttrValue, usize>,
+    /// Counts the started async validations, to identify outdated ones.
+    async_runs: Rc<Cell<usize>>,
+    submitting: bool,
+    submit_count: usize,
+    submit_error: Option<String>,
+}
+
+impl<T: Clone> Clone for FormState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+            initial: self.initial.clone(),
+            values: self.values.clone(),
+            touched: self.touched.clone(),
+            errors: self.errors.clone(),
+            async_errors: self.async_errors.clone(),
+            async_checked: self.async_checked.clone(),
+            validating: self.validating.clone(),
+            async_runs: self.async_runs.clone(),
+            submitting: self.submitting,
+            submit_count: self.submit_count,
+            submit_error: self.submit_error.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for FormState<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.fields, &other.fields)
+            && self.initial == other.initial
+            && self.values == other.values
+            && self.touched == other.touched
+            && self.errors == other.errors
+            && self.async_errors == other.async_errors
+            && self.async_checked == other.async_checked
+            && self.validating == other.validating
+            && Rc::ptr_eq(&self.async_runs, &other.async_runs)
+            && self.submitting == other.submitting
+            && self.submit_count == other.submit_count
+            && self.submit_error == other.submit_error
+    }
+}
+
+impl<T: Clone> FormState<T> {
+    fn new(fields: Rc<Vec<Field<T>>>, initial: T, async_runs: Rc<Cell<usize>>) -> Self {
+        let mut state = Self {
+            fields,
+            values: initial.clone(),
+            initial,
+            touched: HashSet::new(),
+            errors: HashMap::new(),
+            async_errors: HashMap::new(),
+            async_checked: HashMap::new(),
+            validating: HashMap::new(),
+            async_runs,
+            submitting: false,
+            submit_count: 0,
+            submit_error: None,
+        };
+        state.validate_all();
+        state
+    }
+
+    fn field(&self, name: &str) -> Option<&Field<T>> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    fn validate_all(&mut self) {
+        self.errors = self
+            .fields
+            .iter()
+            .filter_map(|field| Some((field.name.clone(), field.check(&self.values)?)))
+            .collect();
+    }
+
+    /// Numbers a new async validation run.
+    fn next_run(&self) -> usize {
+        let run = self.async_runs.get() + 1;
+        self.async_runs.set(run);
+        run
+    }
+
+    /// The value of `field` if it passes the sync validators but its async
+    /// validator hasn't checked it yet.
+    fn unchecked_value(&self, field: &Field<T>) -> Option<String> {
+        field.async_validator.as_ref()?;
+        let value = (field.get)(&self.values);
+        let checked = self.async_checked.get(&field.name) == Some(&value);
+        (!checked && field.check(&self.values).is_none()).then_some(value)
+    }
+}
+
+impl<T: Clone + 'static> Reducible for FormState<T> {
+    type Action = Action;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            Action::Set { name, value } => {
+                let Some(field) = state.field(&name) else {
+                    return self;
+                };
+                (field.set.clone())(&mut state.values, value);
+                // other fields may be validated against this one
+                state.validate_all();
+                state.async_errors.remove(&name);
+                state.validating.remove(&name);
+            }
+            Action::Touched(name) => {
+                state.touched.insert(name);
+            }
+            Action::AsyncStarted { name, run } => {
+                // the setter decided on the values of the last render, the
+                // errors here are up to date
+                if state.errors.contains_key(&name) {
+                    return self;
+                }
+                state.validating.insert(name, run);
+            }
+            Action::AsyncFinished {
+                name,
+                run,
+                value,
+                result,
+            } => {
+                if state.validating.get(&name) != Some(&run) {
+                    return self;
+                }
+                state.validating.remove(&name);
+                state.async_checked.insert(name.clone(), value);
+                if let Err(error) = result {
+                    state.async_errors.insert(name, error);
+                }
+            }
+            Action::SubmitAttempted => {
+                state.touched = state.fields.iter().map(|f| f.name.clone()).collect();
+                state.submit_count += 1;
+                state.submit_error = None;
+                state.validate_all();
+            }
+            Action::SubmitStarted => state.submitting = true,
+            Action::SubmitCancelled => state.submitting = false,
+            Action::SubmitFinished(result) => {
+                state.submitting = false;
+                state.submit_error = result.err();
+            }
+            Action::Reset => state = Self::new(state.fields, state.initial, state.async_runs),
+        }
+        state.into()
+    }
+}
+
+/// Handle of the form state returned by [use_form].
+pub struct Form<T: Clone + 'static> {
+    state: UseReducerHandle<FormState<T>>,
+}
+
+impl<T: Clone + 'static> Clone for Form<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> PartialEq for Form<T> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.state == *other.state
+    }
+}
+
+impl<T: Clone + 'static> fmt::Debug for Form<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Form")
+            .field("touched", &self.state.touched)
+            .field("errors", &self.state.errors)
+            .field("async_errors", &self.state.async_errors)
+            .field("submitting", &self.state.submitting)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static> Form<T> {
+    pub fn values(&self) -> &T {
+        &self.state.values
+    }
+
+    /// # Panics
+    ///
+    /// When there is no field called `name`.
+    pub fn value(&self, name: &str) -> String {
+        (self.expect_field(name).get)(&self.state.values)
+    }
+
+    /// Sets the value of a field and validates it.
+    pub fn set(&self, name: &str, value: String) {
+        let Some(field) = self.state.field(name) else {
+            return;
+        };
+        let name = field.name.clone();
+        let mut values = self.state.values.clone();
+        (field.set)(&mut values, value.clone());
+        // skips validators that would be ignored, values it misses because
+        // other fields changed since the last render are checked on submit
+        let sync_valid = field.check(&values).is_none();
+        let async_validator = field.async_validator.clone();
+
+        self.state.dispatch(Action::Set {
+            name: name.clone(),
+            value: value.clone(),
+        });
+        if let Some(validator) = async_validator.filter(|_| sync_valid) {
+            let run = self.state.next_run();
+            self.state.dispatch(Action::AsyncStarted {
+                name: name.clone(),
+                run,
+            });
+            let dispatcher = self.state.dispatcher();
+            yew::platform::spawn_local(async move {
+                let result = validator(value.clone()).await;
+                dispatcher.dispatch(Action::AsyncFinished {
+                    name,
+                    run,
+                    value,
+                    result,
+                });
+            });
+        }
+    }
+
+    pub fn touch(&self, name: &str) {
+        if let Some(field) = self.state.field(name) {
+            self.state.dispatch(Action::Touched(field.name.clone()));
+        }
+    }
+
+    /// The current error of a field, whether or not it should be shown yet.
+    pub fn error(&self, name: &str) -> Option<&str> {
+        self.state
+            .errors
+            .get(name)
+            .or_else(|| self.state.async_errors.get(name))
+            .map(String::as_str)
+    }
+
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.state.touched.contains(name)
+    }
+
+    /// The value of the field differs from its initial value.
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.state.field(name).is_some_and(|field| {
+            (field.get)(&self.state.values) != (field.get)(&self.state.initial)
+        })
+    }
+
+    /// Any field is dirty.
+    pub fn is_form_dirty(&self) -> bool {
+        self.state
+            .fields
+            .iter()
+            .any(|field| (field.get)(&self.state.values) != (field.get)(&self.state.initial))
+    }
+
+    pub fn is_validating(&self) -> bool {
+        !self.state.validating.is_empty()
+    }
+
+    /// No field has an error and no async validation is running.
+    pub fn is_valid(&self) -> bool {
+        self.state.errors.is_empty() && self.state.async_errors.is_empty() && !self.is_validating()
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        self.state.submitting
+    }
+
+    /// How often the user tried to submit the form.
+    pub fn submit_count(&self) -> usize {
+        self.state.submit_count
+    }
+
+    /// The error of the last submission.
+    pub fn submit_error(&self) -> Option<&str> {
+        self.state.submit_error.as_deref()
+    }
+
+    /// Goes back to the initial values and clears all state.
+    pub fn reset(&self) {
+        self.state.dispatch(Action::Reset);
+    }
+
+    /// The binding of a field for [Tag::bind](crate::vdom::Tag::bind).
+    ///
+    /// # Panics
+    ///
+    /// When there is no field called `name`.
+    pub fn field(&self, name: &str) -> FieldBinding {
+        let field = self.expect_field(name);
+        let touched = self.is_touched(name) || self.state.submit_count > 0;
+        let on_input = {
+            let form = self.clone();
+            let name = field.name.clone();
+            Callback::from(move |value| form.set(&name, value))
+        };
+        let on_blur = {
+            let form = self.clone();
+            let name = field.name.clone();
+            Callback::from(move |_| form.touch(&name))
+        };
+        FieldBinding {
+            name: field.name.clone(),
+            value: (field.get)(&self.state.values).into(),
+            error: self
+                .error(name)
+                .filter(|_| touched)
+                .map(|error| AttrValue::from(error.to_string())),
+            touched: self.is_touched(name),
+            dirty: self.is_dirty(name),
+            validating: self.state.validating.contains_key(name),
+            on_input,
+            on_blur,
+        }
+    }
+
+    /// Submit handler for the `form` element. It touches all fields, runs the
+    /// async validators of values they haven't checked yet and, if the form is
+    /// valid, calls `submit` with the values. Its error is available as
+    /// [submit_error](Self::submit_error).
+    pub fn on_submit<F, Fut>(&self, submit: F) -> Callback<SubmitEvent>
+    where
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        let form = self.clone();
+        let submit = Rc::new(submit);
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let state = &form.state;
+            state.dispatch(Action::SubmitAttempted);
+            let sync_valid = state
+                .fields
+                .iter()
+                .all(|f| f.check(&state.values).is_none());
+            if state.submitting || !sync_valid || !state.async_errors.is_empty() {
+                return;
+            }
+            state.dispatch(Action::SubmitStarted);
+
+            // validations that are still running are restarted, their
+            // results would be ignored
+            let unchecked = state
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    let value = state.unchecked_value(field)?;
+                    let validator = field.async_validator.clone()?;
+                    let run = state.next_run();
+                    state.dispatch(Action::AsyncStarted {
+                        name: field.name.clone(),
+                        run,
+                    });
+                    Some((field.name.clone(), run, value, validator))
+                })
+                .collect::<Vec<_>>();
+            let dispatcher = state.dispatcher();
+            let values = state.values.clone();
+            let submit = submit.clone();
+            yew::platform::spawn_local(async move {
+                let mut valid = true;
+                for (name, run, value, validator) in unchecked {
+                    let result = validator(value.clone()).await;
+                    valid &= result.is_ok();
+                    dispatcher.dispatch(Action::AsyncFinished {
+                        name,
+                        run,
+                        value,
+                        result,
+                    });
+                }
+                match valid {
+                    true => dispatcher.dispatch(Action::SubmitFinished(submit(values).await)),
+                    false => dispatcher.dispatch(Action::SubmitCancelled),
+                }
+            });
+        })
+    }
+
+    fn expect_field(&self, name: &str) -> &Field<T> {
+        self.state
+            .field(name)
+            .unwrap_or_else(|| panic!("no form field called {name}"))
+    }
+}
+
+/// Form state for the fields of `spec`, which is only used on the first
+/// render.
+#[hook]
+pub fn use_form<T, F>(spec: F) -> Form<T>
+where
+    T: Clone + 'static,
+    F: FnOnce() -> FormSpec<T>,
+{
+    let state = use_reducer(move || {
+        let spec = spec();
+        FormState::new(Rc::new(spec.fields), spec.initial, Rc::default())
+    });
+    Form { state }
+}
@@ -0,0 +1,70 @@
+//! Sync validators for [Field](super::Field)s. A validator gets the value of
+//! the field and the values of the whole form, so it can compare fields.
+//!
+//! ```
+//! use yew_utils::form::validate::{email, max_length, min_length, required};
+//!
+//! assert!(required::<()>()("", &()).is_err());
+//! assert!(required::<()>()("x", &()).is_ok());
+//! assert!(min_length::<()>(3)("ab", &()).is_err());
+//! assert!(max_length::<()>(3)("abcd", &()).is_err());
+//! assert!(email::<()>()("ada@example.com", &()).is_ok());
+//! assert!(email::<()>()("ada@example", &()).is_err());
+//! // only `required` rejects empty values
+//! assert!(email::<()>()("", &()).is_ok());
+//! ```
+
+/// The field must not be empty or whitespace only.
+pub fn required<T>() -> impl Fn(&str, &T) -> Result<(), String> {
+    |value, _| match value.trim().is_empty() {
+        true => Err("required".to_string()),
+        false => Ok(()),
+    }
+}
+
+/// At least `min` characters.
+pub fn min_length<T>(min: usize) -> impl Fn(&str, &T) -> Result<(), String> {
+    move |value, _| match value.chars().count() < min {
+        true => Err(format!("at least {min} characters")),
+        false => Ok(()),
+    }
+}
+
+/// At most `max` characters.
+pub fn max_length<T>(max: usize) -> impl Fn(&str, &T) -> Result<(), String> {
+    move |value, _| match value.chars().count() > max {
+        true => Err(format!("at most {max} characters")),
+        false => Ok(()),
+    }
+}
+
+/// Something that looks like an email address, i.e. `local@domain.tld`.
+/// Empty values pass, combine with [required] if needed.
+pub fn email<T>() -> impl Fn(&str, &T) -> Result<(), String> {
+    |value, _| {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(());
+        }
+        let valid = match value.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && !domain.contains('@')
+                    && domain
+                        .split('.')
+                        .collect::<Vec<_>>()
+                        .split_last()
+                        .is_some_and(|(tld, rest)| {
+                            !rest.is_empty()
+                                && !tld.is_empty()
+                                && rest.iter().all(|part| !part.is_empty())
+                        })
+            }
+            None => false,
+        };
+        match valid {
+            true => Ok(()),
+            false => Err("not an email address".to_string()),
+        }
+    }
+}
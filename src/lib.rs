@@ -39,6 +39,18 @@
 //! }
 //! ```
 //!
+//! `portal(host)` and `portal_to_body()` render their children into another element,
+//! e.g. for menus or dialogs that need to escape the overflow or stacking context of
+//! their parent: `portal_to_body().append(div().class("menu").text("..."))`.
+//!
+//! `suspense(fallback)` wraps children in a `yew::Suspense`, and `async_view(future, loading, ok, err)`
+//! renders a loading state, then a view of the future's result. With `.deps(..)` the future is
+//! only restarted when the deps change, and stale futures are dropped.
+//!
+//! Inputs, textareas and selects bind to `use_state` handles with `bind_value`, checkboxes
+//! with `bind_checked`. `bind_number`, `bind_date` (a [Date](date::Date)) and `bind_parsed` parse
+//! the input and report values that don't parse as a `ParseError`.
+//!
 //! ## yew_utils::components
 //!
 //! A set of component. I'll likely add more over time. Currently it includes:
@@ -95,6 +107,81 @@
 //! # }
 //! ```
 //!
+//! For typed rows with sortable columns (click on a header to cycle through
+//! ascending, descending and unsorted, shift-click to sort by multiple columns)
+//! use [DataTable](components::table::DataTable) together with
+//! [Column](components::table::Column) definitions.
+//!
+//! ### [VirtualList](components::virtual_list::VirtualList)
+//!
+//! Renders only the children in view (plus an overscan) of long lists, with fixed
+//! or measured row heights. [Table](components::table::Table) and
+//! [DataTable](components::table::DataTable) support the same via
+//! [VirtualScroll](components::table::VirtualScroll).
+//!
+//! ### [Modal](components::modal::Modal)
+//!
+//! Modal dialog on the native `<dialog>` element with header, body and footer, closed
+//! by Escape or a click on the backdrop. Focus stays in the dialog and the page
+//! doesn't scroll while it is open.
+//!
+//! ### [Tabs](components::tabs::Tabs)
+//!
+//! Tab list with lazily rendered panels, controlled or uncontrolled, with arrow key
+//! navigation and `tablist`/`tab`/`tabpanel` roles. `keep_mounted` keeps panels
+//! mounted once they were shown.
+//!
+//! ### [ToastProvider](components::toast::ToastProvider)
+//!
+//! App-wide notifications: components below a ToastProvider push toasts with
+//! `use_toaster().push(Toast::success("Saved"))`. Toasts have a severity, optional action
+//! buttons and dismiss themselves after a timeout that pauses on hover. They are
+//! rendered into a portal on the document body.
+//!
+//! ### [Popover](components::popover::Popover) and [Tooltip](components::tooltip::Tooltip)
+//!
+//! Floating content anchored to an element via a `NodeRef`, placed on a side with start,
+//! center or end alignment. It flips to the opposite side and shifts along the anchor to
+//! stay in the viewport, and follows the anchor on scroll and resize. The placement math
+//! is in [placement](components::placement).
+//!
+//! ### [Fields](components::fields)
+//!
+//! Labelled form fields with help text, error message and disabled state: `TextField`,
+//! `TextArea`, `NumberField` (parsed into e.g. `f64` or `i64`, with min, max and step),
+//! `DateField`, `CheckboxField`, `Switch` and `RadioGroup`. Text fields can be bound to
+//! a form with `TextFieldProps::bound("Email", form.field("email"))`.
+//!
+//! ### [Slider](components::slider::Slider) and [RangeSlider](components::slider::RangeSlider)
+//!
+//! Sliders with float values, steps, marks and optional value labels. RangeSlider has two
+//! thumbs and emits `(lo, hi)`. Thumbs are native range inputs, so they work with the
+//! keyboard.
+//!
+//! ### [DatePicker](components::date_picker::DatePicker) and [DateRangePicker](components::date_picker::DateRangePicker)
+//!
+//! Calendar grid for a typed [Date](date::Date) or a range of dates, with month and year
+//! navigation, keyboard navigation, min/max and disabled dates. Names and the first day of
+//! the week follow the locale of the browser or the `locale` prop.
+//!
+//! ### [FileUpload](components::file_upload::FileUpload)
+//!
+//! Drop zone and file dialog for one or more files, with `accept` and size filters and a
+//! list of the picked files with image thumbnails. Files are read into bytes or text with a
+//! progress bar while reading.
+//!
+//! ### [Accordion](components::accordion::Accordion)
+//!
+//! Collapsible sections with single or multiple expanded sections, controlled or
+//! uncontrolled, with animated height transitions, `aria-expanded`/`aria-controls` on the
+//! headers and arrow key navigation between them.
+//!
+//! ## yew_utils::form
+//!
+//! [use_form](form::use_form) keeps the values of a form together with touched, dirty and
+//! error state per field, sync and async validators and the submission state. Inputs are
+//! bound to fields with `input().bind(form.field("email"))`.
+//!
 //! ## features
 //!
 //! ### `yew-router`
@@ -117,8 +204,17 @@
 //! ```
 //!
 //! Will replace the [`yew_utils::components::drop_down::DropDown`](components::drop_down::DropDown) component with a version that is styled with [mui-css](https://www.muicss.com/), in particular see [mui-css dropdowns](https://www.muicss.com/docs/v1/css-js/dropdowns).
+//!
+//! Likewise [`yew_utils::components::tabs`](components::tabs) is replaced by a version
+//! styled as [mui-css tabs](https://www.muicss.com/docs/v1/css-js/tabs).
+//!
+//! Tables get the `mui-table` class only when this feature is enabled, the content of
+//! modals, toasts and popovers is styled as a `mui-panel`. Form fields use the
+//! [mui-css forms](https://www.muicss.com/docs/v1/css-js/forms) markup.
 
 // https://www.muicss.com/
 
 pub mod components;
+pub mod date;
+pub mod form;
 pub mod vdom;
@@ -0,0 +1,226 @@
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+use yew::prelude::*;
+use yew::virtual_dom as vdom;
+
+type BoxedFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>>>>;
+
+/// Renders a loading state until a future resolves, then a view of its result.
+///
+/// A new future replaces the running one, which is dropped, whenever the
+/// `deps` change. Without `deps` the first future is the only one, unless
+/// [restart_on_render](Self::restart_on_render) is set. Futures only start when
+/// they are polled, so creating one per render is cheap.
+pub struct AsyncView<T, E> {
+    props: AsyncViewProps<T, E>,
+}
+
+impl<T: 'static, E: 'static> AsyncView<T, E> {
+    pub(crate) fn new(
+        future: impl Future<Output = Result<T, E>> + 'static,
+        loading: impl Into<Html>,
+        ok: impl Fn(&T) -> Html + 'static,
+        err: impl Fn(&E) -> Html + 'static,
+    ) -> Self {
+        Self {
+            props: AsyncViewProps {
+                future: Rc::new(RefCell::new(Some(Box::pin(future)))),
+                deps: vdom::Key::from(""),
+                restart: false,
+                loading: loading.into(),
+                ok: Rc::new(ok),
+                err: Rc::new(err),
+            },
+        }
+    }
+
+    /// Only start the future when `deps` changed since the last render.
+    #[must_use]
+    pub fn deps(mut self, deps: impl Into<vdom::Key>) -> Self {
+        self.props.deps = deps.into();
+        self
+    }
+
+    /// Start the future on every render of the parent, regardless of `deps`.
+    #[must_use]
+    pub fn restart_on_render(mut self) -> Self {
+        self.props.restart = true;
+        self
+    }
+
+    pub fn to_vnode(self) -> vdom::VNode {
+        super::comp_with::<AsyncViewComp<T, E>>(self.props).to_vnode()
+    }
+}
+
+impl<T: 'static, E: 'static> From<AsyncView<T, E>> for vdom::VNode {
+    fn from(builder: AsyncView<T, E>) -> Self {
+        builder.to_vnode()
+    }
+}
+
+impl<T: 'static, E: 'static> From<AsyncView<T, E>> for yew::Children {
+    fn from(builder: AsyncView<T, E>) -> Self {
+        yew::Children::new([builder.into()].to_vec())
+    }
+}
+
+#[derive(Default)]
+struct CancelHandle {
+    cancelled: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl CancelHandle {
+    fn cancel(&self) {
+        self.cancelled.set(true);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Resolves to `None` once it is cancelled, dropping the wrapped future.
+struct Cancellable<T, E> {
+    future: Option<BoxedFuture<T, E>>,
+    handle: Rc<CancelHandle>,
+}
+
+impl<T, E> Future for Cancellable<T, E> {
+    type Output = Option<Result<T, E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let Some(future) = this
+            .future
+            .as_mut()
+            .filter(|_| !this.handle.cancelled.get())
+        else {
+            this.future = None;
+            return Poll::Ready(None);
+        };
+        *this.handle.waker.borrow_mut() = Some(cx.waker().clone());
+        future.as_mut().poll(cx).map(Some)
+    }
+}
+
+#[derive(Properties)]
+struct AsyncViewProps<T, E> {
+    /// Taken when the future is started.
+    future: Rc<RefCell<Option<BoxedFuture<T, E>>>>,
+    deps: vdom::Key,
+    restart: bool,
+    loading: Html,
+    ok: Rc<dyn Fn(&T) -> Html>,
+    err: Rc<dyn Fn(&E) -> Html>,
+}
+
+impl<T, E> PartialEq for AsyncViewProps<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.future, &other.future)
+            && self.deps == other.deps
+            && self.restart == other.restart
+            && self.loading == other.loading
+            && Rc::ptr_eq(&self.ok, &other.ok)
+            && Rc::ptr_eq(&self.err, &other.err)
+    }
+}
+
+enum State<T, E> {
+    Loading,
+    Done(Result<T, E>),
+}
+
+struct AsyncViewComp<T, E> {
+    state: State<T, E>,
+    running: Option<Rc<CancelHandle>>,
+    /// Identifies the running future, results of older ones are ignored.
+    generation: usize,
+}
+
+enum Msg<T, E> {
+    Resolved(usize, Result<T, E>),
+}
+
+impl<T: 'static, E: 'static> AsyncViewComp<T, E> {
+    fn cancel(&mut self) {
+        if let Some(handle) = self.running.take() {
+            handle.cancel();
+        }
+    }
+
+    fn start(&mut self, ctx: &Context<Self>) {
+        let Some(future) = ctx.props().future.borrow_mut().take() else {
+            return;
+        };
+        self.cancel();
+        self.state = State::Loading;
+        self.generation += 1;
+
+        let handle = Rc::new(CancelHandle::default());
+        self.running = Some(handle.clone());
+        let link = ctx.link().clone();
+        let generation = self.generation;
+        yew::platform::spawn_local(async move {
+            let future = Cancellable {
+                future: Some(future),
+                handle,
+            };
+            if let Some(result) = future.await {
+                link.send_message(Msg::Resolved(generation, result));
+            }
+        });
+    }
+}
+
+impl<T: 'static, E: 'static> Component for AsyncViewComp<T, E> {
+    type Message = Msg<T, E>;
+    type Properties = AsyncViewProps<T, E>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut view = Self {
+            state: State::Loading,
+            running: None,
+            generation: 0,
+        };
+        view.start(ctx);
+        view
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        match &self.state {
+            State::Loading => props.loading.clone(),
+            State::Done(Ok(value)) => (props.ok)(value),
+            State::Done(Err(error)) => (props.err)(error),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Resolved(generation, result) => {
+                if generation != self.generation {
+                    return false;
+                }
+                self.running = None;
+                self.state = State::Done(result);
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let props = ctx.props();
+        if props.restart || props.deps != old_props.deps {
+            self.start(ctx);
+        }
+        true
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        self.cancel();
+    }
+}
@@ -1,11 +1,18 @@
 //! Provides a Rust interface for constructing yew virtual DOM elements.
 
+mod async_view;
 mod comp;
+mod portal;
+mod suspense;
 mod tag;
 mod text;
 
+pub use async_view::AsyncView;
 pub use comp::Comp;
-pub use tag::Tag;
+pub use portal::Portal;
+pub use suspense::SuspenseBuilder;
+pub use tag::{ParseError, Tag};
+pub(crate) use tag::{TagType, TagTypeInput};
 pub use text::Text;
 
 use std::borrow::Cow;
@@ -23,10 +30,20 @@ pub fn input() -> Tag<tag::TagTypeInput> {
     Tag::input()
 }
 
+pub fn textarea() -> Tag<tag::TagTypeTextArea> {
+    Tag::textarea()
+}
+
+pub fn select() -> Tag<tag::TagTypeSelect> {
+    Tag::select()
+}
+
 pub fn checkbox() -> Tag<tag::TagTypeInput> {
     input().type_checkbox()
 }
 
+/// A bare range input, see [Slider](crate::components::slider::Slider) for a
+/// component with float values, steps, marks and a range mode.
 pub fn slider(min: i32, max: i32, value: i32) -> Tag<tag::TagTypeInput> {
     input()
         .attr("type", "range")
@@ -35,6 +52,60 @@ pub fn slider(min: i32, max: i32, value: i32) -> Tag<tag::TagTypeInput> {
         .value(value.to_string())
 }
 
+/// Renders the appended children into `host`.
+///
+/// Up to 0.4 this built the obsolete `<portal>` element, which is still
+/// available as `tag("portal")`.
+pub fn portal(host: web_sys::Element) -> Portal {
+    Portal::new(Some(host))
+}
+
+/// Renders the appended children into the body of the document, or in place
+/// if there is no document (e.g. during server side rendering).
+pub fn portal_to_body() -> Portal {
+    let body = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.body());
+    Portal::new(body.map(Into::into))
+}
+
+/// Shows `fallback` while a child component is suspended.
+pub fn suspense(fallback: impl Into<vdom::VNode>) -> SuspenseBuilder {
+    SuspenseBuilder::new(fallback)
+}
+
+/// Renders `loading` until `future` resolves, then `ok` or `err` of its result.
+///
+/// ```no_run
+/// use yew::prelude::*;
+/// use yew_utils::vdom::*;
+///
+/// async fn load_user(id: u32) -> Result<String, String> {
+///     Ok(format!("user {id}"))
+/// }
+///
+/// #[function_component(User)]
+/// fn user() -> Html {
+///     let id = 7;
+///     async_view(
+///         load_user(id),
+///         p().text("loading…"),
+///         |name: &String| h1().text(name.clone()).into(),
+///         |err: &String| p().class("error").text(err.clone()).into(),
+///     )
+///     .deps(id)
+///     .into()
+/// }
+/// ```
+pub fn async_view<T: 'static, E: 'static>(
+    future: impl std::future::Future<Output = Result<T, E>> + 'static,
+    loading: impl Into<vdom::VNode>,
+    ok: impl Fn(&T) -> vdom::VNode + 'static,
+    err: impl Fn(&E) -> vdom::VNode + 'static,
+) -> AsyncView<T, E> {
+    AsyncView::new(future, loading, ok, err)
+}
+
 pub fn comp_with<'a, T>(props: T::Properties) -> Comp<'a, T>
 where
     T: yew::BaseComponent,
@@ -154,7 +225,6 @@ known_tag!(p);
 known_tag!(param);
 known_tag!(picture);
 known_tag!(plaintext);
-known_tag!(portal);
 known_tag!(pre);
 known_tag!(progress);
 known_tag!(q);
@@ -167,7 +237,6 @@ known_tag!(s);
 known_tag!(samp);
 known_tag!(script);
 known_tag!(section);
-known_tag!(select);
 known_tag!(shadow);
 known_tag!(slot);
 known_tag!(small);
@@ -185,7 +254,6 @@ known_tag!(table);
 known_tag!(tbody);
 known_tag!(td);
 known_tag!(template);
-known_tag!(textarea);
 known_tag!(tfoot);
 known_tag!(th);
 known_tag!(thead);
@@ -0,0 +1,54 @@
+use web_sys::Element;
+use yew::virtual_dom as vdom;
+
+/// Better interface for [vdom::VPortal]
+///
+/// Renders its children into another element than the parent, e.g. to escape
+/// the overflow or stacking context of the parent. Without a host (see
+/// [portal_to_body](super::portal_to_body)) the children are rendered in place.
+pub struct Portal {
+    host: Option<Element>,
+    children: vdom::VList,
+}
+
+impl Portal {
+    pub(crate) fn new(host: Option<Element>) -> Self {
+        Self {
+            host,
+            children: vdom::VList::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn append(mut self, node: impl Into<vdom::VNode>) -> Self {
+        self.children.add_child(node.into());
+        self
+    }
+
+    #[must_use]
+    pub fn append_all(mut self, nodes: impl IntoIterator<Item = impl Into<vdom::VNode>>) -> Self {
+        self.children
+            .add_children(nodes.into_iter().map(|ea| ea.into()));
+        self
+    }
+
+    pub fn to_vnode(self) -> vdom::VNode {
+        let children = vdom::VNode::VList(self.children);
+        match self.host {
+            Some(host) => vdom::VNode::VPortal(vdom::VPortal::new(children, host)),
+            None => children,
+        }
+    }
+}
+
+impl From<Portal> for vdom::VNode {
+    fn from(portal: Portal) -> Self {
+        portal.to_vnode()
+    }
+}
+
+impl From<Portal> for yew::Children {
+    fn from(portal: Portal) -> Self {
+        yew::Children::new([portal.into()].to_vec())
+    }
+}
@@ -0,0 +1,51 @@
+use yew::virtual_dom as vdom;
+
+/// Better interface for [yew::Suspense]
+///
+/// Named so that it doesn't clash with `yew::Suspense` from `yew::prelude`.
+pub struct SuspenseBuilder {
+    fallback: vdom::VNode,
+    children: vdom::VList,
+}
+
+impl SuspenseBuilder {
+    pub(crate) fn new(fallback: impl Into<vdom::VNode>) -> Self {
+        Self {
+            fallback: fallback.into(),
+            children: vdom::VList::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn append(mut self, node: impl Into<vdom::VNode>) -> Self {
+        self.children.add_child(node.into());
+        self
+    }
+
+    #[must_use]
+    pub fn append_all(mut self, nodes: impl IntoIterator<Item = impl Into<vdom::VNode>>) -> Self {
+        self.children
+            .add_children(nodes.into_iter().map(|ea| ea.into()));
+        self
+    }
+
+    pub fn to_vnode(self) -> vdom::VNode {
+        super::comp_with::<yew::Suspense>(yew::suspense::SuspenseProps {
+            children: vdom::VNode::VList(self.children),
+            fallback: self.fallback,
+        })
+        .to_vnode()
+    }
+}
+
+impl From<SuspenseBuilder> for vdom::VNode {
+    fn from(builder: SuspenseBuilder) -> Self {
+        builder.to_vnode()
+    }
+}
+
+impl From<SuspenseBuilder> for yew::Children {
+    fn from(builder: SuspenseBuilder) -> Self {
+        yew::Children::new([builder.into()].to_vec())
+    }
+}
@@ -1,15 +1,38 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
 use std::{borrow::Cow, marker::PhantomData, rc::Rc};
-use yew::html::IntoEventCallback;
+use yew::html::{IntoEventCallback, TargetCast};
 use yew::virtual_dom as vdom;
+use yew::{Callback, UseStateHandle};
+
+use crate::date::Date;
+
+/// A value of an input that couldn't be parsed by one of the `bind_*` methods.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub input: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {:?}", self.message, self.input)
+    }
+}
 
 // -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
 
 pub struct TagTypeDefault;
 pub struct TagTypeInput;
+pub struct TagTypeTextArea;
+pub struct TagTypeSelect;
 
 pub trait TagType {}
 impl TagType for TagTypeDefault {}
 impl TagType for TagTypeInput {}
+impl TagType for TagTypeTextArea {}
+impl TagType for TagTypeSelect {}
 
 // -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
 
@@ -60,6 +83,18 @@ where
         self.attr("class", class)
     }
 
+    /// Sets the class attribute from [yew::Classes], does nothing if they are
+    /// empty.
+    #[must_use]
+    pub fn classes(self, classes: impl Into<yew::Classes>) -> Self {
+        let classes = classes.into();
+        if classes.is_empty() {
+            self
+        } else {
+            self.class(classes.to_string())
+        }
+    }
+
     #[must_use]
     pub fn id(self, id: impl Into<vdom::AttrValue>) -> Self {
         self.attr("id", id)
@@ -126,16 +161,8 @@ where
     T: TagType,
 {
     fn update_listeners(&mut self) {
-        // argh, yeq implements the listeners list statically
-        #[rustfmt::skip]
-        self.tag.set_listeners(match self.listeners.as_slice() {
-            [a] => Box::new([a.clone()]),
-            [a, b] => Box::new([a.clone(), b.clone()]),
-            [a, b, c] => Box::new([a.clone(), b.clone(), c.clone()]),
-            [a, b, c, d] => Box::new([a.clone(), b.clone(), c.clone(), d.clone()]),
-            [a, b, c, d, e] => Box::new([a.clone(), b.clone(), c.clone(), d.clone(), e.clone()]),
-            _ => unimplemented!("More listeners than expected... probably need to extend this."),
-        });
+        self.tag
+            .set_listeners(self.listeners.clone().into_boxed_slice());
     }
 
     add_event_listener!(onabort);
@@ -255,6 +282,124 @@ impl Tag<TagTypeInput> {
         self
     }
 
+    /// Binds the input to a field of a [Form](crate::form::Form): sets its
+    /// name, value and `aria-invalid` and updates the field on input and blur.
+    #[must_use]
+    pub fn bind(self, field: crate::form::FieldBinding) -> Self {
+        let on_input = field.on_input;
+        let on_blur = field.on_blur;
+        self.attr("name", field.name)
+            .value(field.value)
+            .attr("aria-invalid", field.error.is_some().to_string())
+            .oninput(move |e: yew::InputEvent| {
+                on_input.emit(
+                    e.target_unchecked_into::<web_sys::HtmlInputElement>()
+                        .value(),
+                )
+            })
+            .onblur(move |_| on_blur.emit(()))
+    }
+
+    /// Two-way binding of the value to `state`.
+    #[must_use]
+    pub fn bind_value(self, state: UseStateHandle<String>) -> Self {
+        self.value((*state).clone())
+            .oninput(move |e: yew::InputEvent| {
+                state.set(
+                    e.target_unchecked_into::<web_sys::HtmlInputElement>()
+                        .value(),
+                )
+            })
+    }
+
+    /// Two-way binding of the checked state of a checkbox or radio button.
+    #[must_use]
+    pub fn bind_checked(self, state: UseStateHandle<bool>) -> Self {
+        self.checked(*state).onchange(move |e: yew::Event| {
+            state.set(
+                e.target_unchecked_into::<web_sys::HtmlInputElement>()
+                    .checked(),
+            )
+        })
+    }
+
+    /// Two-way binding of a value that is parsed from the input. Values that
+    /// don't parse leave `state` unchanged and are reported to `on_error`.
+    #[must_use]
+    pub fn bind_parsed<V>(self, state: UseStateHandle<V>, on_error: Callback<ParseError>) -> Self
+    where
+        V: FromStr + Display + 'static,
+        V::Err: Display,
+    {
+        self.value(state.to_string())
+            .oninput(move |e: yew::InputEvent| {
+                let input = e
+                    .target_unchecked_into::<web_sys::HtmlInputElement>()
+                    .value();
+                match input.trim().parse() {
+                    Ok(value) => state.set(value),
+                    Err(err) => on_error.emit(ParseError {
+                        message: err.to_string(),
+                        input,
+                    }),
+                }
+            })
+    }
+
+    /// Two-way binding of a number, e.g. of a `type_number()` input or a
+    /// [slider](super::slider). See [bind_parsed](Self::bind_parsed).
+    ///
+    /// ```no_run
+    /// use yew::prelude::*;
+    /// use yew_utils::vdom::*;
+    ///
+    /// #[function_component(Volume)]
+    /// fn volume() -> Html {
+    ///     let volume = use_state(|| 50);
+    ///     let error = use_state(|| None);
+    ///     let on_error = {
+    ///         let error = error.clone();
+    ///         Callback::from(move |e: ParseError| error.set(Some(e.to_string())))
+    ///     };
+    ///     div()
+    ///         .append(slider(0, 100, *volume).bind_number(volume.clone(), on_error.clone()))
+    ///         .append(input().type_number().bind_number(volume, on_error))
+    ///         .append_all((*error).clone().map(|error| span().text(error)))
+    ///         .into()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn bind_number<N>(self, state: UseStateHandle<N>, on_error: Callback<ParseError>) -> Self
+    where
+        N: FromStr + Display + 'static,
+        N::Err: Display,
+    {
+        self.bind_parsed(state, on_error)
+    }
+
+    /// Two-way binding of a `type_date()` input, an empty input is `None`. See
+    /// [bind_parsed](Self::bind_parsed).
+    #[must_use]
+    pub fn bind_date(
+        self,
+        state: UseStateHandle<Option<Date>>,
+        on_error: Callback<ParseError>,
+    ) -> Self {
+        let value = state.map(|date| date.to_string()).unwrap_or_default();
+        self.value(value).oninput(move |e: yew::InputEvent| {
+            let input = e
+                .target_unchecked_into::<web_sys::HtmlInputElement>()
+                .value();
+            if input.is_empty() {
+                return state.set(None);
+            }
+            match input.parse() {
+                Ok(date) => state.set(Some(date)),
+                Err(message) => on_error.emit(ParseError { input, message }),
+            }
+        })
+    }
+
     pub fn type_button(self) -> Self {
         self.attr("type", "button")
     }
@@ -347,3 +492,119 @@ impl Tag<TagTypeInput> {
         self.attr("type", "week")
     }
 }
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+impl Tag<TagTypeTextArea> {
+    pub(crate) fn textarea() -> Self {
+        Tag::with_props("textarea")
+    }
+
+    #[must_use]
+    pub fn value(mut self, value: impl yew::html::IntoPropValue<Option<vdom::AttrValue>>) -> Self {
+        self.tag.set_value(value);
+        self
+    }
+
+    /// Two-way binding of the value to `state`.
+    #[must_use]
+    pub fn bind_value(self, state: UseStateHandle<String>) -> Self {
+        self.value((*state).clone())
+            .oninput(move |e: yew::InputEvent| {
+                state.set(
+                    e.target_unchecked_into::<web_sys::HtmlTextAreaElement>()
+                        .value(),
+                )
+            })
+    }
+}
+
+// -=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-=-
+
+impl Tag<TagTypeSelect> {
+    pub(crate) fn select() -> Self {
+        Tag::with_props("select")
+    }
+
+    /// Two-way binding of the value to `state`, i.e. the `value` attribute of
+    /// the selected option. Call this after appending the options.
+    #[must_use]
+    pub fn bind_value(mut self, state: UseStateHandle<String>) -> Self {
+        if let Some(children) = self.tag.children_mut() {
+            select_option(children.to_vlist_mut(), &state);
+        }
+        self.onchange(move |e: yew::Event| {
+            state.set(
+                e.target_unchecked_into::<web_sys::HtmlSelectElement>()
+                    .value(),
+            )
+        })
+    }
+}
+
+/// Marks the options (also in option groups) with `value` as selected.
+///
+/// Browsers ignore changes of the `selected` attribute once the user picked an
+/// option, so options whose selection changes get a new key and are replaced.
+/// Only options without a key of their own and with a unique value are keyed.
+fn select_option(options: &mut vdom::VList, value: &str) {
+    let mut counts = HashMap::new();
+    for_each_option(options, &mut |option| {
+        *counts.entry(option_value(option)).or_insert(0) += 1;
+    });
+    for_each_option(options, &mut |option| {
+        let option_value = option_value(option);
+        let selected = option_value == value;
+        let attributes = option.attributes.get_mut_index_map();
+        attributes.swap_remove("selected");
+        if selected {
+            option.add_attribute("selected", "selected");
+        }
+        if option.key.is_none() && counts[&option_value] == 1 {
+            option.key = Some(format!("{option_value}:{selected}").into());
+        }
+    });
+}
+
+/// Calls `f` with the options in `options`, also those in option groups.
+fn for_each_option(options: &mut vdom::VList, f: &mut impl FnMut(&mut vdom::VTag)) {
+    for child in options.iter_mut() {
+        let vdom::VNode::VTag(tag) = child else {
+            continue;
+        };
+        match tag.tag() {
+            "optgroup" => {
+                if let Some(children) = tag.children_mut() {
+                    for_each_option(children.to_vlist_mut(), f);
+                }
+            }
+            "option" => f(tag),
+            _ => {}
+        }
+    }
+    options.recheck_fully_keyed();
+}
+
+/// The `value` attribute of `option`, or like in browsers its text with
+/// collapsed whitespace.
+fn option_value(option: &vdom::VTag) -> String {
+    let value = option
+        .attributes
+        .iter()
+        .find(|(key, _)| *key == "value")
+        .map(|(_, value)| value.to_string());
+    value.unwrap_or_else(|| {
+        fn text(node: &vdom::VNode, out: &mut String) {
+            match node {
+                vdom::VNode::VText(text) => out.push_str(&text.text),
+                vdom::VNode::VList(list) => list.iter().for_each(|node| text(node, out)),
+                _ => {}
+            }
+        }
+        let mut out = String::new();
+        if let Some(children) = option.children() {
+            text(children, &mut out);
+        }
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    })
+}